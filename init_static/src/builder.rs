@@ -0,0 +1,116 @@
+use std::sync::Mutex;
+
+use crate::__private::{Deps, Init, InitFn};
+use crate::{Result, Symbol};
+
+/// Statics registered at runtime, or `None` once they have been merged into the registry.
+static DYNAMIC_INIT: Mutex<Option<Vec<Init>>> = Mutex::new(Some(Vec::new()));
+
+/// Takes the statics registered at runtime, closing the registry to further registrations.
+pub(crate) fn take_dynamic() -> Vec<Init> {
+    DYNAMIC_INIT.lock().unwrap().take().unwrap_or_default()
+}
+
+fn push_dynamic(init: Init) {
+    DYNAMIC_INIT
+        .lock()
+        .unwrap()
+        .as_mut()
+        .expect("statics cannot be registered after initialization has started")
+        .push(init);
+}
+
+/// A builder for registering the initializer of a static at runtime, without going through
+/// [`init_static!`](crate::init_static!).
+///
+/// This is useful for plugin systems or generated code, where the statics are only known at
+/// runtime. The static itself is typically declared with [`InitStatic!`](crate::InitStatic!) and
+/// set from the registered closure. Registered statics take part in dependency resolution like
+/// any other: they may depend on statics declared with the macro, and statics declared with the
+/// macro may depend on them.
+///
+/// Registration must happen before [`init_static()`](crate::init_static()) (or any other function
+/// inspecting the registry) is first called, and panics otherwise.
+///
+/// # Example
+///
+/// ```standalone_crate
+/// use init_static::{InitStatic, StaticBuilder, init_static};
+///
+/// init_static! {
+///     static BASE: u32 = "1".parse()?;
+/// }
+///
+/// static PLUGIN: InitStatic<u32> = InitStatic!(PLUGIN);
+///
+/// #[tokio::main]
+/// async fn main() {
+///     StaticBuilder::new(InitStatic::symbol(&PLUGIN))
+///         .depends_on(InitStatic::symbol(&BASE))
+///         .init(|| {
+///             InitStatic::init(&PLUGIN, *BASE + 1);
+///             Ok(())
+///         });
+///     init_static().await.unwrap();
+///     assert_eq!(*PLUGIN, 2);
+/// }
+/// ```
+#[must_use = "the static is only registered once `init` is called"]
+pub struct StaticBuilder {
+    symbol: &'static Symbol,
+    deps: Vec<&'static Symbol>,
+}
+
+impl StaticBuilder {
+    /// Creates a builder for the static identified by `symbol`.
+    pub fn new(symbol: &'static Symbol) -> Self {
+        Self {
+            symbol,
+            deps: Vec::new(),
+        }
+    }
+
+    /// Declares that the static depends on the static identified by `symbol`.
+    pub fn depends_on(mut self, symbol: &'static Symbol) -> Self {
+        self.deps.push(symbol);
+        self
+    }
+
+    /// Registers `init` as the synchronous initializer of the static.
+    pub fn init(self, init: impl Fn() -> Result<()> + Send + Sync + 'static) {
+        self.register(InitFn::Sync(Box::leak(Box::new(init))));
+    }
+
+    /// Registers `init` as the asynchronous initializer of the static.
+    #[cfg(feature = "async")]
+    pub fn init_async<F>(self, init: impl Fn() -> F + Send + Sync + 'static)
+    where
+        F: Future<Output = Result<()>> + Send + 'static,
+    {
+        self.register(InitFn::Async(Box::leak(Box::new(move || {
+            Box::pin(init()) as crate::__private::BoxFuture<Result<()>>
+        }))));
+    }
+
+    fn register(self, init: InitFn) {
+        push_dynamic(Init {
+            symbol: self.symbol,
+            init,
+            deps: Deps::Symbols(self.deps.leak()),
+            weak_deps: &[],
+            unmanaged: &Vec::new,
+        });
+    }
+}
+
+/// Registers `init` as the synchronous initializer of the static identified by `symbol`, depending
+/// on `deps`. This is a shorthand for [`StaticBuilder`].
+pub fn register_init(
+    symbol: &'static Symbol,
+    deps: &[&'static Symbol],
+    init: impl Fn() -> Result<()> + Send + Sync + 'static,
+) {
+    deps.iter()
+        .fold(StaticBuilder::new(symbol), |builder, &dep| builder.depends_on(dep))
+        .init(init);
+}
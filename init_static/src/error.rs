@@ -1,5 +1,25 @@
+use std::any::Any;
+use std::time::Duration;
+
 use crate::Symbol;
 
+/// The error type of init functions and of [`init_static()`](crate::init_static()).
+///
+/// This is [`anyhow::Error`] when the `anyhow` feature is enabled (the default), and a boxed
+/// [`std::error::Error`] otherwise.
+#[cfg(feature = "anyhow")]
+pub type Error = anyhow::Error;
+
+/// The error type of init functions and of [`init_static()`](crate::init_static()).
+///
+/// This is [`anyhow::Error`] when the `anyhow` feature is enabled (the default), and a boxed
+/// [`std::error::Error`] otherwise.
+#[cfg(not(feature = "anyhow"))]
+pub type Error = Box<dyn std::error::Error + Send + Sync>;
+
+/// A [`Result`](std::result::Result) whose error type defaults to [`Error`].
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
 /// Error type returned by [`init_static()`](crate::init_static()) when initialization fails.
 ///
 /// This enum represents the various failure modes that can occur during the static initialization
@@ -7,21 +27,36 @@ use crate::Symbol;
 ///
 /// ## Note on Execution Errors
 ///
-/// Errors returned by initialization expressions (e.g., `"42".parse()?`) are **NOT**
-/// wrapped in this enum. Instead, [`init_static()`](crate::init_static()) returns
-/// [`anyhow::Result<()>`] directly, which preserves the original error's backtrace for better
-/// debugging.
+/// With the `anyhow` feature (enabled by default), errors returned by initialization expressions
+/// (e.g., `"42".parse()?`) are **NOT** wrapped in this enum. Instead,
+/// [`init_static()`](crate::init_static()) returns the [`anyhow::Error`] directly, which preserves
+/// the original error's backtrace for better debugging. The error is annotated with a context
+/// message naming the failing [`Symbol`] (e.g.
+/// `failed to initialize my_app::FOO (at src/main.rs:4:12)`), so the original message is available
+/// as its source. Any context attached by the init expression itself is kept below it, so the full
+/// chain is available through `anyhow::Error::chain` or the alternate `{:#}` format. Without the
+/// feature, they are wrapped in `InitError::Execution` instead, whose
+/// [`source`](std::error::Error::source) is the original error.
 ///
-/// To distinguish between error types, use [`anyhow::Error::downcast`] or
-/// [`anyhow::Error::downcast_ref`].
+/// To distinguish between error types, use `downcast` or `downcast_ref` on the returned
+/// [`Error`].
 #[derive(Debug)]
 pub enum InitError {
     /// A static symbol was defined multiple times.
     ///
-    /// This typically occurs when the same [`init_static!`](crate::init_static!) block is included
-    /// multiple times, or when two statics in different modules have the exact same source
-    /// location metadata (which should not happen in normal usage).
-    Ambiguous { symbol: &'static Symbol },
+    /// This occurs when the same static is registered more than once, e.g. when a static declared
+    /// with [`init_static!`](crate::init_static!) is also registered with
+    /// [`StaticBuilder`](crate::StaticBuilder). Statics are identified by [`Symbol::key`], so
+    /// statics sharing the same source location metadata (e.g. generated from the same macro call
+    /// site) are not considered ambiguous.
+    ///
+    /// Since the key is derived from the address of the symbol, all colliding entries share the
+    /// same `symbol`. `count` is the number of entries registered for it.
+    ///
+    /// Declaring two statics with the same name in the same scope (e.g. by including the same
+    /// [`init_static!`](crate::init_static!) block twice) is already rejected at compile time,
+    /// since each static is emitted as a regular `static` item alongside its registry entry.
+    Ambiguous { symbol: &'static Symbol, count: usize },
 
     /// A circular dependency was detected among statics.
     ///
@@ -29,6 +64,9 @@ pub enum InitError {
     /// on static A. The initialization system cannot determine a valid order to initialize such
     /// statics.
     ///
+    /// `path` lists the statics forming the cycle in order, where each one depends on the next and
+    /// the last one depends on the first. It starts at the static declared first in source order.
+    ///
     /// # Example
     ///
     /// ```ignore
@@ -40,24 +78,204 @@ pub enum InitError {
     ///     static B: u32 = *A + 1;
     /// }
     /// ```
-    Circular { symbols: Vec<&'static Symbol> },
+    Circular { path: Vec<&'static Symbol> },
+
+    /// A static depends on itself.
+    ///
+    /// This is reported separately from [`Circular`](Self::Circular), since it is usually caused by
+    /// a typo in the initialization expression.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use init_static::init_static;
+    ///
+    /// init_static! {
+    ///     static A: u32 = *A + 1;
+    /// }
+    /// ```
+    SelfDependency { symbol: &'static Symbol },
+
+    /// A static depends on an [`InitStatic`](crate::InitStatic) that is not registered with
+    /// [`init_static!`](crate::init_static!).
+    ///
+    /// This is only reported with [`MissingDepPolicy::Error`](crate::MissingDepPolicy::Error). See
+    /// [`set_missing_dep_policy()`](crate::set_missing_dep_policy()).
+    MissingDependency {
+        dependent: &'static Symbol,
+        missing: &'static Symbol,
+    },
+
+    /// An async static was encountered by [`init_static_sync()`](crate::init_static_sync()).
+    ///
+    /// Use [`init_static()`](crate::init_static()) or
+    /// [`init_static_blocking()`](crate::init_static_blocking()) if the program contains async
+    /// statics.
+    AsyncInNonAsyncContext { symbol: &'static Symbol },
+
+    /// A static declared with `#[context(...)]` was initialized without a context of the expected
+    /// type, which must be passed to [`init_static_with()`](crate::init_static_with()).
+    MissingContext {
+        symbol: &'static Symbol,
+        type_name: &'static str,
+    },
+
+    /// An async static did not finish initializing within the duration set by
+    /// [`set_timeout()`](crate::set_timeout()).
+    Timeout { symbol: &'static Symbol, elapsed: Duration },
+
+    /// The whole initialization process did not finish within the duration set by
+    /// [`set_total_timeout()`](crate::set_total_timeout()).
+    TotalTimeout { elapsed: Duration },
+
+    /// A static depends on a static of a later stage passed to
+    /// [`init_static_stages()`](crate::init_static_stages()).
+    StageOrder {
+        symbol: &'static Symbol,
+        dependency: &'static Symbol,
+    },
+
+    /// A static is listed before one of its dependencies (or the dependency is not listed) in the
+    /// order passed to [`init_static_ordered()`](crate::init_static_ordered()).
+    OrderViolation {
+        symbol: &'static Symbol,
+        dependency: &'static Symbol,
+    },
+
+    /// Some [`InitStatic`](crate::InitStatic) values were never initialized, as reported by
+    /// [`verify_all_initialized()`](crate::verify_all_initialized()).
+    Uninitialized { symbols: Vec<&'static Symbol> },
+
+    /// One or more statics failed to initialize while
+    /// [`set_collect_errors()`](crate::set_collect_errors()) was enabled.
+    ///
+    /// `errors` lists each failing static along with its error, and `skipped` lists the statics
+    /// that were not initialized because one of their dependencies failed, each along with the
+    /// failing static it transitively depends on.
+    Multiple {
+        errors: Vec<(&'static Symbol, Error)>,
+        skipped: Vec<(&'static Symbol, &'static Symbol)>,
+    },
+
+    /// An init function panicked while [`set_catch_panics()`](crate::set_catch_panics()) was
+    /// enabled.
+    ///
+    /// `message` is the panic message, if the panic payload is a string.
+    Panic { symbol: &'static Symbol, message: String },
+
+    /// An initialization expression returned an error.
+    ///
+    /// This is only used without the `anyhow` feature, in place of annotating the error with a
+    /// context message.
+    #[cfg(not(feature = "anyhow"))]
+    Execution { symbol: &'static Symbol, source: Error },
 }
 
 impl std::fmt::Display for InitError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::Ambiguous { symbol } => {
-                write!(f, "Symbol {symbol} is defined multiple times.")
+            Self::Ambiguous { symbol, count } => {
+                write!(f, "Symbol {symbol} is defined {count} times.")
+            }
+            Self::Circular { path } => {
+                writeln!(f, "Circular dependency detected:")?;
+                for (i, symbol) in path.iter().enumerate() {
+                    let arrow = if i == 0 { "" } else { "-> " };
+                    writeln!(f, "    {arrow}{symbol}")?;
+                }
+                writeln!(f, "    -> {}", path[0])
+            }
+            Self::SelfDependency { symbol } => {
+                write!(f, "Static {symbol} depends on itself.")
             }
-            Self::Circular { symbols } => {
-                writeln!(f, "Circular dependency detected among:")?;
+            Self::MissingDependency { dependent, missing } => {
+                write!(f, "Static {dependent} depends on {missing}, which is not registered.")
+            }
+            Self::AsyncInNonAsyncContext { symbol } => {
+                write!(f, "Symbol {symbol} requires async initialization.")
+            }
+            Self::MissingContext { symbol, type_name } => {
+                write!(f, "Symbol {symbol} requires an init context of type {type_name}.")
+            }
+            Self::Timeout { symbol, elapsed } => {
+                write!(f, "Symbol {symbol} timed out after {elapsed:?}.")
+            }
+            Self::TotalTimeout { elapsed } => {
+                write!(f, "Initialization timed out after {elapsed:?}.")
+            }
+            Self::StageOrder { symbol, dependency } => {
+                write!(
+                    f,
+                    "Symbol {symbol} depends on {dependency}, which belongs to a later stage."
+                )
+            }
+            Self::OrderViolation { symbol, dependency } => {
+                write!(f, "Symbol {symbol} is ordered before its dependency {dependency}.")
+            }
+            Self::Uninitialized { symbols } => {
+                writeln!(f, "Uninitialized statics:")?;
                 for symbol in symbols {
                     writeln!(f, "    {symbol}")?;
                 }
                 Ok(())
             }
+            Self::Panic { symbol, message } => {
+                write!(f, "Symbol {symbol} panicked: {message}")
+            }
+            #[cfg(not(feature = "anyhow"))]
+            Self::Execution { symbol, .. } => {
+                write!(f, "Failed to initialize {symbol}.")
+            }
+            Self::Multiple { errors, skipped } => {
+                writeln!(f, "Failed to initialize:")?;
+                for (symbol, error) in errors {
+                    writeln!(f, "    {symbol}: {error}")?;
+                }
+                if !skipped.is_empty() {
+                    writeln!(f, "Skipped due to failed dependencies:")?;
+                    for (symbol, because) in skipped {
+                        writeln!(f, "    {symbol} (because {because} failed)")?;
+                    }
+                }
+                Ok(())
+            }
         }
     }
 }
 
-impl std::error::Error for InitError {}
+impl std::error::Error for InitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(not(feature = "anyhow"))]
+            Self::Execution { source, .. } => Some(&**source),
+            _ => None,
+        }
+    }
+}
+
+/// Converts the payload of a panic caught in the init function of `symbol` into an error.
+pub(crate) fn panic_error(symbol: &'static Symbol, payload: Box<dyn Any + Send>) -> Error {
+    let message = match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&'static str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "Box<dyn Any>".to_owned(),
+        },
+    };
+    InitError::Panic { symbol, message }.into()
+}
+
+/// Annotates an error returned by the initialization of `symbol`.
+///
+/// Without the `anyhow` feature, errors raised by the initialization process itself (such as
+/// [`InitError::Timeout`]) are returned as is, so that they can still be downcast to [`InitError`].
+pub(crate) fn execution_error(symbol: &'static Symbol, error: Error) -> Error {
+    #[cfg(feature = "anyhow")]
+    return error.context(format!("failed to initialize {symbol}"));
+    #[cfg(not(feature = "anyhow"))]
+    if error.is::<InitError>() {
+        error
+    } else {
+        InitError::Execution { symbol, source: error }.into()
+    }
+}
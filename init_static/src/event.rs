@@ -0,0 +1,78 @@
+#[cfg(feature = "async")]
+use std::collections::VecDeque;
+#[cfg(feature = "async")]
+use std::sync::Mutex;
+#[cfg(feature = "async")]
+use std::task::{Context, Poll};
+
+#[cfg(feature = "async")]
+use futures_util::task::AtomicWaker;
+
+use crate::Symbol;
+
+/// An event emitted during initialization, observable via
+/// [`set_progress_hook()`](crate::set_progress_hook()) or
+/// [`init_static_events()`](crate::init_static_events()).
+///
+/// Events are emitted in the order they happen. `*End` events are emitted whether or not the
+/// initializer succeeded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InitEvent {
+    /// Initialization has started. `total` is the number of registered statics.
+    Started { total: usize },
+
+    /// A synchronous static is about to be initialized.
+    SyncBegin { symbol: &'static Symbol },
+
+    /// A synchronous static has finished initializing.
+    SyncEnd { symbol: &'static Symbol },
+
+    /// An asynchronous static has started initializing.
+    AsyncBegin { symbol: &'static Symbol },
+
+    /// An asynchronous static has finished initializing.
+    AsyncEnd { symbol: &'static Symbol },
+
+    /// A static reported intermediate progress while initializing, via
+    /// [`report_progress()`](crate::report_progress()).
+    ///
+    /// `progress` is the value passed by the initializer, conventionally between `0.0` and `1.0`.
+    Progress { symbol: &'static Symbol, progress: f64 },
+
+    /// Every static has been processed.
+    ///
+    /// This is not emitted if initialization is aborted by an error.
+    Finished,
+}
+
+/// The events buffered for the stream returned by [`init_static_events()`](crate::init_static_events()).
+#[cfg(feature = "async")]
+#[derive(Default)]
+pub(crate) struct EventQueue {
+    /// The pending events, and whether no more events will be pushed.
+    state: Mutex<(VecDeque<InitEvent>, bool)>,
+    waker: AtomicWaker,
+}
+
+#[cfg(feature = "async")]
+impl EventQueue {
+    pub(crate) fn push(&self, event: InitEvent) {
+        self.state.lock().unwrap().0.push_back(event);
+        self.waker.wake();
+    }
+
+    pub(crate) fn close(&self) {
+        self.state.lock().unwrap().1 = true;
+        self.waker.wake();
+    }
+
+    pub(crate) fn poll_next(&self, cx: &mut Context<'_>) -> Poll<Option<InitEvent>> {
+        self.waker.register(cx.waker());
+        let (events, closed) = &mut *self.state.lock().unwrap();
+        match events.pop_front() {
+            Some(event) => Poll::Ready(Some(event)),
+            None if *closed => Poll::Ready(None),
+            None => Poll::Pending,
+        }
+    }
+}
@@ -1,8 +1,13 @@
+use std::collections::{HashMap, HashSet};
 #[doc = include_str!("../README.md")]
 use std::fmt::Debug;
 use std::fmt::Display;
+use std::hash::{Hash, Hasher};
 use std::ops::{Deref, DerefMut};
-use std::sync::OnceLock;
+use std::sync::{LazyLock, Mutex, OnceLock, PoisonError};
+
+use crate::__private::TypeNameFn;
+use crate::InitOutcome;
 
 /// Represents the source location and identity of a static variable declared via
 /// [`init_static!`](crate::init_static!).
@@ -16,9 +21,9 @@ use std::sync::OnceLock;
 /// use init_static::Symbol;
 ///
 /// let symbol: &Symbol = Symbol!(MY_VALUE);
-/// println!("{symbol}"); // MY_VALUE (at src/main.rs:10:1)
+/// println!("{symbol}"); // my_app::MY_VALUE (at src/main.rs:10:1)
 /// ```
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(PartialEq, Eq, Hash)]
 pub struct Symbol {
     /// The source file path where this static is declared.
     ///
@@ -40,10 +45,94 @@ pub struct Symbol {
     pub ident: &'static str,
 }
 
+impl Symbol {
+    /// Creates a new `Symbol` without type information.
+    #[inline]
+    pub const fn new(file: &'static str, line: u32, column: u32, module: &'static str, ident: &'static str) -> Self {
+        Self {
+            file,
+            line,
+            column,
+            module,
+            ident,
+        }
+    }
+
+    /// Returns the name of the static's value type, if known.
+    ///
+    /// The type is known for symbols created with `Symbol!(MY_VALUE: Type)`, which includes every
+    /// static declared with [`init_static!`](crate::init_static!). It is not stored in the symbol
+    /// itself, but looked up by [key](Symbol::key) in a registry filled at link time.
+    pub fn type_name(&self) -> Option<&'static str> {
+        let key = SymbolKey(self as *const Self as usize);
+        TYPE_NAME_MAP.get(&key).map(|f| f())
+    }
+
+    /// Returns the key that uniquely identifies this symbol.
+    ///
+    /// Unlike [`PartialEq`], which compares source location metadata, the key is derived from the
+    /// address of the symbol itself. Each [`Symbol!`](crate::Symbol!) invocation declares its own
+    /// `static`, so statics generated from the same macro call site (and therefore sharing the same
+    /// metadata) still have distinct keys. The key is stable for the lifetime of the process.
+    #[inline]
+    pub fn key(&'static self) -> SymbolKey {
+        SymbolKey(self as *const Self as usize)
+    }
+}
+
+/// A comparable key that uniquely identifies a [`Symbol`], as returned by [`Symbol::key`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SymbolKey(usize);
+
+impl Debug for Symbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Symbol")
+            .field("file", &self.file)
+            .field("line", &self.line)
+            .field("column", &self.column)
+            .field("module", &self.module)
+            .field("ident", &self.ident)
+            .field("type_name", &self.type_name())
+            .finish()
+    }
+}
+
+impl PartialOrd for Symbol {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Symbols are ordered by source location, i.e. `(file, line, column)`, with the module path and
+/// identifier as tie-breakers.
+impl Ord for Symbol {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.file, self.line, self.column, self.module, self.ident).cmp(&(
+            other.file,
+            other.line,
+            other.column,
+            other.module,
+            other.ident,
+        ))
+    }
+}
+
 impl Display for Symbol {
+    /// Formats the symbol as `module::IDENT (at file:line:column)`.
+    ///
+    /// With the alternate flag (`{:#}`), the type name is included when known, e.g.
+    /// `module::IDENT: Type (at file:line:column)`.
     #[inline]
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} (at {}:{}:{})", self.ident, self.file, self.line, self.column)
+        write!(f, "{}::{}", self.module, self.ident)?;
+        if f.alternate()
+            && let Some(type_name) = self.type_name()
+        {
+            write!(f, ": {type_name}")?;
+        }
+        write!(f, " (at {}:{}:{})", self.file, self.line, self.column)
     }
 }
 
@@ -68,23 +157,39 @@ impl Display for Symbol {
 ///
 /// This macro returns a [`&'static Symbol`](Symbol) reference, which is suitable for use in
 /// static contexts and error reporting.
+///
+/// The type of the static can be passed as `Symbol!(MY_VALUE: Type)` to also record its
+/// [`type_name`](Symbol::type_name):
+///
+/// ```
+/// use init_static::Symbol;
+///
+/// let symbol: &Symbol = Symbol!(MY_VALUE: u32);
+/// assert_eq!(symbol.type_name(), Some("u32"));
+/// assert!(format!("{symbol:#}").contains("::MY_VALUE: u32 (at "));
+/// ```
 #[macro_export]
 macro_rules! Symbol {
-    ($ident:ident) => {
-        &$crate::Symbol {
-            file: file!(),
-            line: line!(),
-            column: column!(),
-            module: module_path!(),
-            ident: stringify!($ident),
-        }
-    };
+    ($ident:ident) => {{
+        static SYMBOL: $crate::Symbol =
+            $crate::Symbol::new(file!(), line!(), column!(), module_path!(), stringify!($ident));
+        &SYMBOL
+    }};
+    ($ident:ident: $ty:ty) => {{
+        static SYMBOL: $crate::Symbol =
+            $crate::Symbol::new(file!(), line!(), column!(), module_path!(), stringify!($ident));
+        #[$crate::__private::distributed_slice($crate::__private::TYPE_NAMES)]
+        #[linkme(crate = $crate::__private::linkme)]
+        static TYPE_NAME: $crate::__private::TypeName = (&SYMBOL, ::std::any::type_name::<$ty>);
+        &SYMBOL
+    }};
 }
 
 /// Creates a new uninitialized [`InitStatic<T>`] instance with source location metadata.
 ///
 /// This macro is a convenience wrapper around [`InitStatic::new`] that automatically
-/// captures the source location using the [`Symbol!`](crate::Symbol!) macro.
+/// captures the source location using the [`Symbol!`](crate::Symbol!) macro. Like
+/// [`Symbol!`](crate::Symbol!), it accepts an optional type as `InitStatic!(MY_VALUE: Type)`.
 ///
 /// # Example
 ///
@@ -98,21 +203,128 @@ macro_rules! Symbol {
 /// // The static is uninitialized and will panic if accessed before initialization.
 /// // Use `InitStatic::init(&MY_CONFIG, value)` to initialize it.
 /// ```
+///
+/// # Note
+///
+/// Every invocation registers its [`Symbol`] so that
+/// [`verify_all_initialized()`](crate::verify_all_initialized()) can detect statics that were never
+/// initialized.
 #[macro_export]
 macro_rules! InitStatic {
-    ($ident:ident) => {
-        $crate::InitStatic::new($crate::Symbol!($ident))
+    ($ident:ident $(: $ty:ty)?) => {{
+        #[$crate::__private::distributed_slice($crate::__private::SYMBOLS)]
+        #[linkme(crate = $crate::__private::linkme)]
+        static SYMBOL: &$crate::Symbol = $crate::Symbol!($ident $(: $ty)?);
+        $crate::InitStatic::new(SYMBOL)
+    }};
+}
+
+/// Reads a dependency from an init expression of [`init_static!`](crate::init_static!) or
+/// [`init_thread_local!`](crate::init_thread_local!), returning a reference to its value.
+///
+/// Dependencies are always initialized before the statics that depend on them, so plain `*FOO` or
+/// `&*FOO` is just as valid. `dep!(FOO)` differs only in its panic message, which names the static
+/// being initialized if the dependency was somehow not detected. It cannot be used outside an init
+/// expression.
+///
+/// # Example
+///
+/// ```
+/// use init_static::{dep, init_static};
+///
+/// init_static! {
+///     static NAMES: Vec<String> = vec!["foo".to_string(), "bar".to_string()];
+///     static COUNT: usize = dep!(NAMES).len();
+/// }
+/// ```
+#[macro_export]
+macro_rules! dep {
+    ($path:path) => {
+        $crate::InitStatic::expect(&$path, __init_static_dependent())
     };
 }
 
+/// The type names registered by `Symbol!(MY_VALUE: Type)`, keyed by symbol.
+static TYPE_NAME_MAP: LazyLock<HashMap<SymbolKey, TypeNameFn>> = LazyLock::new(|| {
+    crate::__private::TYPE_NAMES
+        .iter()
+        .map(|&(symbol, type_name)| (symbol.key(), type_name))
+        .collect()
+});
+
+/// Symbols of the [`InitStatic`] values that have been initialized, used by
+/// [`verify_all_initialized()`](crate::verify_all_initialized()).
+pub(crate) static INITIALIZED_SYMBOLS: LazyLock<Mutex<HashSet<SymbolKey>>> = LazyLock::new(Default::default);
+
+/// The outcome of every static processed by [`init_static()`](crate::init_static()), as returned
+/// by [`init_outcome()`](crate::init_outcome()). Failures are also reported when the static is
+/// accessed. Entries are overwritten when a retry processes the static again.
+pub(crate) static INIT_OUTCOMES: LazyLock<Mutex<HashMap<SymbolKey, InitOutcome>>> = LazyLock::new(Default::default);
+
+/// A function building the panic message for an access to an uninitialized static.
+pub(crate) type UninitHook = fn(&'static Symbol) -> String;
+
+/// The hook set by [`set_uninit_hook()`](crate::set_uninit_hook()).
+pub(crate) static UNINIT_HOOK: Mutex<Option<UninitHook>> = Mutex::new(None);
+
+/// Panics on an access to an uninitialized static, with the reason its initializer failed if it
+/// did, or the message returned by the hook set by [`set_uninit_hook()`](crate::set_uninit_hook())
+/// if any.
+#[cold]
+fn uninit_panic(symbol: &'static Symbol) -> ! {
+    let outcome = INIT_OUTCOMES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&symbol.key())
+        .cloned();
+    match outcome {
+        Some(InitOutcome::Failed(error)) => panic!("init of {symbol} failed: {error}"),
+        Some(InitOutcome::Skipped(because)) => panic!("init of {symbol} was skipped because {because} failed"),
+        _ => {}
+    }
+    let hook = *UNINIT_HOOK.lock().unwrap_or_else(PoisonError::into_inner);
+    match hook {
+        Some(hook) => panic!("{}", hook(symbol)),
+        None => panic!(
+            "access to uninitialized init_static: {symbol} (hint: call `init_static()` or `InitStatic::init` first)"
+        ),
+    }
+}
+
+/// The symbol shared by the values created with [`InitStatic::anonymous`].
+static ANONYMOUS_SYMBOL: Symbol = Symbol::new("", 0, 0, "", "<anonymous>");
+
+/// Whether an [`InitStatic`] has been initialized, as returned by [`InitStatic::state`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InitState {
+    /// The static has not been initialized yet, and dereferencing it would panic.
+    Uninitialized,
+
+    /// The static holds a value.
+    Initialized,
+}
+
 /// A wrapper around [`OnceLock`] providing safe initialization and [`Deref`] support to mimic the
 /// ergonomics of [`lazy_static!`](lazy_static::lazy_static!).
 ///
 /// Values must be initialized exactly once, either via [`InitStatic::init`] or by calling
-/// [`init_static()`](crate::init_static()). Accessing an uninitialized value will panic.
+/// [`init_static()`](crate::init_static()). Accessing an uninitialized value will panic. If its
+/// initializer failed during [`init_static()`](crate::init_static()), the panic message includes
+/// the error, e.g. `init of FOO (at ...) failed: invalid digit found in string`.
+///
+/// A static created with [`InitStatic::with_value`], as [`init_static!`](crate::init_static!) does
+/// for statics initialized by a literal, already holds its value and is never initialized.
 pub struct InitStatic<T> {
     symbol: &'static Symbol,
-    inner: OnceLock<T>,
+    inner: Inner<T>,
+}
+
+/// The storage of an [`InitStatic`].
+enum Inner<T> {
+    /// A value given at compile time via [`InitStatic::with_value`].
+    Const(T),
+    /// A value initialized at runtime.
+    Once(OnceLock<T>),
 }
 
 impl<T> InitStatic<T> {
@@ -124,18 +336,205 @@ impl<T> InitStatic<T> {
     pub const fn new(symbol: &'static Symbol) -> Self {
         Self {
             symbol,
-            inner: OnceLock::new(),
+            inner: Inner::Once(OnceLock::new()),
         }
     }
 
+    /// Creates a new `InitStatic` that is already initialized with `value`.
+    ///
+    /// [`init_static!`](crate::init_static!) uses this for statics initialized by a literal, which
+    /// therefore need no init function. Such a static is not registered for initialization, and
+    /// does not take part in the dependency graph: it is not counted by
+    /// [`registered_count()`](crate::registered_count()) or
+    /// [`registered_symbols()`](crate::registered_symbols()), and does not appear in
+    /// [`init_order()`](crate::init_order()) or [`dependency_graph()`](crate::dependency_graph()).
+    /// Dependencies on it are still satisfied.
+    #[inline]
+    pub const fn with_value(symbol: &'static Symbol, value: T) -> Self {
+        Self {
+            symbol,
+            inner: Inner::Const(value),
+        }
+    }
+
+    /// Creates a new uninitialized `InitStatic` with a placeholder [`Symbol`], for values created at
+    /// runtime whose source location does not matter.
+    ///
+    /// All such values share the same symbol, whose identifier is `<anonymous>` and whose location
+    /// is empty. They cannot be told apart in panic messages, and must not be registered for
+    /// initialization.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use init_static::InitStatic;
+    ///
+    /// let value = InitStatic::anonymous();
+    /// InitStatic::init(&value, 42);
+    /// assert_eq!(*value, 42);
+    /// assert_eq!(InitStatic::symbol(&value).ident, "<anonymous>");
+    /// ```
+    #[inline]
+    pub const fn anonymous() -> Self {
+        Self::new(&ANONYMOUS_SYMBOL)
+    }
+
     /// Initializes the given static value.
     ///
     /// This must be called exactly once. Subsequent calls will panic.
     #[inline]
     pub fn init(this: &Self, value: T) {
-        this.inner
-            .set(value)
-            .unwrap_or_else(|_| panic!("double initialization of init_static: {}", this.symbol));
+        Self::try_init(this, value).unwrap_or_else(|_| panic!("double initialization of init_static: {}", this.symbol));
+    }
+
+    /// Initializes the given static value, returning `Err(value)` if it was already initialized.
+    ///
+    /// This is the non-panicking counterpart of [`InitStatic::init`].
+    #[inline]
+    pub fn try_init(this: &Self, value: T) -> Result<(), T> {
+        let Inner::Once(cell) = &this.inner else {
+            return Err(value);
+        };
+        cell.set(value)?;
+        INITIALIZED_SYMBOLS.lock().unwrap().insert(this.symbol.key());
+        Ok(())
+    }
+
+    /// Initializes the given static value if it is not initialized yet, returning whether `value`
+    /// was stored. Otherwise, `value` is dropped.
+    ///
+    /// This is useful for idempotent initialization, e.g. in tests or plugins racing to set the same
+    /// static, where only the first caller wins.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use init_static::InitStatic;
+    ///
+    /// static VALUE: InitStatic<u32> = InitStatic!(VALUE);
+    ///
+    /// assert!(InitStatic::set_if_unset(&VALUE, 1));
+    /// assert!(!InitStatic::set_if_unset(&VALUE, 2));
+    /// assert_eq!(*VALUE, 1);
+    /// ```
+    #[inline]
+    pub fn set_if_unset(this: &Self, value: T) -> bool {
+        Self::try_init(this, value).is_ok()
+    }
+
+    /// Returns a reference to the value if it has been initialized, or `None` otherwise.
+    ///
+    /// Unlike [`Deref`], this never panics, which makes it suitable for code paths that may run
+    /// before [`init_static()`](crate::init_static()) completes.
+    #[inline]
+    pub fn get(this: &Self) -> Option<&T> {
+        match &this.inner {
+            Inner::Const(value) => Some(value),
+            Inner::Once(cell) => cell.get(),
+        }
+    }
+
+    /// Returns a reference to the value, panicking with a message naming `dependent` if it has not
+    /// been initialized.
+    ///
+    /// This is meant for reading a dependency from the init expression of `dependent`, where the
+    /// value is only missing if the dependency was not detected. See [`dep!`](crate::dep!).
+    #[inline]
+    #[track_caller]
+    pub fn expect<'a>(this: &'a Self, dependent: &Symbol) -> &'a T {
+        Self::get(this).unwrap_or_else(|| {
+            panic!(
+                "{dependent} accessed its dependency {} before it was initialized",
+                this.symbol
+            )
+        })
+    }
+
+    /// Returns whether this static has been initialized.
+    ///
+    /// Unlike [`is_initialized()`](crate::is_initialized()), which reports whether
+    /// [`init_static()`](crate::init_static()) has been called, this checks the individual static.
+    #[inline]
+    pub fn is_set(this: &Self) -> bool {
+        Self::get(this).is_some()
+    }
+
+    /// Returns the [`InitState`] of this static.
+    ///
+    /// This is equivalent to [`InitStatic::is_set`], but is convenient to match on, particularly
+    /// when `T` is itself an `Option` and `None` could be mistaken for an uninitialized static.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use init_static::{InitState, InitStatic};
+    ///
+    /// static PROXY: InitStatic<Option<String>> = InitStatic!(PROXY);
+    ///
+    /// assert_eq!(InitStatic::state(&PROXY), InitState::Uninitialized);
+    /// InitStatic::init(&PROXY, None);
+    /// assert_eq!(InitStatic::state(&PROXY), InitState::Initialized);
+    /// ```
+    #[inline]
+    pub fn state(this: &Self) -> InitState {
+        if Self::is_set(this) {
+            InitState::Initialized
+        } else {
+            InitState::Uninitialized
+        }
+    }
+
+    /// Returns a reference to the value, initializing it with `f` if it has not been initialized.
+    ///
+    /// This gives the static [`LazyLock`](std::sync::LazyLock)-like fallback semantics, which is
+    /// useful for statics that are usually initialized by [`init_static()`](crate::init_static())
+    /// but may be accessed in contexts (such as unit tests) where the registry never runs.
+    ///
+    /// # Note
+    ///
+    /// Mixing this with the registry is racy: if `f` wins, the registry's subsequent
+    /// [`InitStatic::init`] call will panic with a double initialization error.
+    #[inline]
+    pub fn get_or_init(this: &Self, f: impl FnOnce() -> T) -> &T {
+        match &this.inner {
+            Inner::Const(value) => value,
+            Inner::Once(cell) => cell.get_or_init(|| {
+                let value = f();
+                INITIALIZED_SYMBOLS.lock().unwrap().insert(this.symbol.key());
+                value
+            }),
+        }
+    }
+
+    /// Takes the value out of this static, leaving it uninitialized.
+    ///
+    /// Statics are never dropped by default. Dropping the returned value runs its [`Drop`]
+    /// implementation, which is how `#[drop_on_deinit]` flushes values in
+    /// [`deinit_static()`](crate::deinit_static()).
+    ///
+    /// # Safety Note
+    ///
+    /// Obtaining `&mut` access to a static requires declaring it as `static mut`. Doing so while
+    /// other threads hold references to the value is undefined behavior.
+    #[inline]
+    pub fn take(this: &mut Self) -> Option<T> {
+        INITIALIZED_SYMBOLS.lock().unwrap().remove(&this.symbol.key());
+        match std::mem::replace(&mut this.inner, Inner::Once(OnceLock::new())) {
+            Inner::Const(value) => Some(value),
+            Inner::Once(mut cell) => cell.take(),
+        }
+    }
+
+    /// Clears the value of this static, returning it if it was initialized.
+    ///
+    /// This is intended for test harnesses that need to re-run
+    /// [`init_static()`](crate::init_static()), together with
+    /// [`reset_init_options()`](crate::reset_init_options()). It is equivalent to
+    /// [`InitStatic::take`].
+    #[cfg(feature = "reset")]
+    #[inline]
+    pub fn reset(this: &mut Self) -> Option<T> {
+        Self::take(this)
     }
 
     /// Returns the [`Symbol`] associated with this static, containing source location metadata.
@@ -153,18 +552,19 @@ impl<T> Deref for InitStatic<T> {
 
     #[inline]
     fn deref(&self) -> &Self::Target {
-        self.inner
-            .get()
-            .unwrap_or_else(|| panic!("access to uninitialized init_static: {}", self.symbol))
+        Self::get(self).unwrap_or_else(|| uninit_panic(self.symbol))
     }
 }
 
 impl<T> DerefMut for InitStatic<T> {
     #[inline]
     fn deref_mut(&mut self) -> &mut Self::Target {
-        self.inner
-            .get_mut()
-            .unwrap_or_else(|| panic!("access to uninitialized init_static: {}", self.symbol))
+        let symbol = self.symbol;
+        match &mut self.inner {
+            Inner::Const(value) => Some(value),
+            Inner::Once(cell) => cell.get_mut(),
+        }
+        .unwrap_or_else(|| uninit_panic(symbol))
     }
 }
 
@@ -182,6 +582,112 @@ impl<T: Display> Display for InitStatic<T> {
     }
 }
 
+impl<T: PartialEq> PartialEq for InitStatic<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Eq> Eq for InitStatic<T> {}
+
+impl<T: PartialOrd> PartialOrd for InitStatic<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        (**self).partial_cmp(&**other)
+    }
+}
+
+impl<T: Ord> Ord for InitStatic<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (**self).cmp(&**other)
+    }
+}
+
+impl<T: Hash> Hash for InitStatic<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).hash(state)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a InitStatic<T>
+where
+    &'a T: IntoIterator,
+{
+    type Item = <&'a T as IntoIterator>::Item;
+    type IntoIter = <&'a T as IntoIterator>::IntoIter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        (**self).into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut InitStatic<T>
+where
+    &'a mut T: IntoIterator,
+{
+    type Item = <&'a mut T as IntoIterator>::Item;
+    type IntoIter = <&'a mut T as IntoIterator>::IntoIter;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        (**self).into_iter()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for InitStatic<T> {
+    /// Serializes the inner value transparently.
+    ///
+    /// Returns a serialization error if the value has not been initialized.
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match Self::get(self) {
+            Some(value) => value.serialize(serializer),
+            None => Err(serde::ser::Error::custom(format_args!(
+                "access to uninitialized init_static: {}",
+                self.symbol
+            ))),
+        }
+    }
+}
+
+/// Values that carry the [`Symbol`] of a static, such as [`struct@InitStatic`] and
+/// [`InitThreadLocal`](crate::InitThreadLocal).
+///
+/// This exposes [`InitStatic::symbol`] as a method, which allows writing generic diagnostics over
+/// any static. The associated function form remains available and does not require importing this
+/// trait.
+///
+/// # Example
+///
+/// ```
+/// use init_static::{HasSymbol, init_static};
+///
+/// fn describe(value: &impl HasSymbol) -> String {
+///     value.symbol().to_string()
+/// }
+///
+/// init_static! {
+///     static VALUE: u32 = 42;
+/// }
+///
+/// assert!(describe(&VALUE).contains("::VALUE (at "));
+/// ```
+pub trait HasSymbol {
+    /// Returns the [`Symbol`] of the static, containing source location metadata.
+    fn symbol(&self) -> &'static Symbol;
+}
+
+impl<T> HasSymbol for InitStatic<T> {
+    #[inline]
+    fn symbol(&self) -> &'static Symbol {
+        self.symbol
+    }
+}
+
 /// A helper trait for extracting [`Symbol`] information from static variables.
 ///
 /// This trait uses [autoref-based specialization](https://github.com/dtolnay/case-studies/blob/master/autoref-specialization/README.md)
@@ -190,23 +696,65 @@ impl<T: Display> Display for InitStatic<T> {
 ///
 /// # How It Works
 ///
-/// The trait has two implementations:
+/// The trait has the following implementations:
 ///
-/// - For [`InitStatic<T>`]: Returns `Some(&Symbol)` containing the source location metadata.
+/// - For [`InitStatic<T>`]: Returns `Some(&Symbol)` containing the source location metadata, or
+///   `None` if it was created with [`InitStatic::with_value`].
+/// - For [`LazyLock<T, F>`] (and `once_cell::sync::Lazy<T, F>` with the `once_cell` feature):
+///   Returns `None`, but forces the value when the dependent static is about to initialize.
 /// - For `&T` (any reference): Returns `None`, indicating this is not a tracked static.
 ///
 /// When the macro generates dependency-checking code, Rust's method resolution prefers
-/// the more specific [`InitStatic<T>`] implementation over the blanket `&T` implementation,
-/// allowing automatic detection of [`init_static!`](crate::init_static!) variables.
+/// the more specific implementations over the blanket `&T` implementation, allowing automatic
+/// detection of [`init_static!`](crate::init_static!) variables.
 pub trait MaybeInitStatic {
     /// Returns the [`Symbol`] for this static if it is an [`InitStatic`], or `None` otherwise.
     fn __get_symbol(&self) -> Option<&'static Symbol>;
+
+    /// Forces the value of a lazily initialized static, or does nothing otherwise.
+    #[inline]
+    fn __force(&self) {}
+
+    /// Returns whether this is a value whose initialization is not managed by `init_static`, i.e.
+    /// neither an [`InitStatic`] nor a lazily initialized static.
+    #[inline]
+    fn __is_unmanaged(&self) -> bool {
+        false
+    }
 }
 
 impl<T> MaybeInitStatic for InitStatic<T> {
     #[inline]
     fn __get_symbol(&self) -> Option<&'static Symbol> {
-        Some(self.symbol)
+        match self.inner {
+            Inner::Const(_) => None,
+            Inner::Once(_) => Some(self.symbol),
+        }
+    }
+}
+
+impl<T, F: FnOnce() -> T> MaybeInitStatic for LazyLock<T, F> {
+    #[inline]
+    fn __get_symbol(&self) -> Option<&'static Symbol> {
+        None
+    }
+
+    #[inline]
+    fn __force(&self) {
+        LazyLock::force(self);
+    }
+}
+
+#[cfg(feature = "once_cell")]
+impl<T, F: FnOnce() -> T> MaybeInitStatic for once_cell::sync::Lazy<T, F> {
+    #[inline]
+    fn __get_symbol(&self) -> Option<&'static Symbol> {
+        None
+    }
+
+    #[inline]
+    fn __force(&self) {
+        once_cell::sync::Lazy::force(self);
     }
 }
 
@@ -215,4 +763,9 @@ impl<T> MaybeInitStatic for &T {
     fn __get_symbol(&self) -> Option<&'static Symbol> {
         None
     }
+
+    #[inline]
+    fn __is_unmanaged(&self) -> bool {
+        true
+    }
 }
@@ -0,0 +1,107 @@
+use std::cell::OnceCell;
+use std::fmt::{Debug, Display};
+use std::ops::Deref;
+use std::thread::LocalKey;
+
+use crate::init_static::MaybeInitStatic;
+use crate::{HasSymbol, Symbol};
+
+/// A thread-local counterpart of [`InitStatic`](crate::InitStatic), declared via
+/// [`init_thread_local!`](crate::init_thread_local!).
+///
+/// Each thread holds its own value, which must be initialized exactly once on that thread, either
+/// via [`InitThreadLocal::init`] or by calling [`init_thread_local()`](crate::init_thread_local()).
+/// Accessing an uninitialized value will panic.
+pub struct InitThreadLocal<T> {
+    symbol: &'static Symbol,
+    inner: OnceCell<T>,
+}
+
+impl<T> InitThreadLocal<T> {
+    /// Creates a new uninitialized `InitThreadLocal`.
+    #[inline]
+    pub const fn new(symbol: &'static Symbol) -> Self {
+        Self {
+            symbol,
+            inner: OnceCell::new(),
+        }
+    }
+
+    /// Initializes the value of the current thread.
+    ///
+    /// This must be called exactly once per thread. Subsequent calls will panic.
+    #[inline]
+    pub fn init(this: &Self, value: T) {
+        Self::try_init(this, value)
+            .unwrap_or_else(|_| panic!("double initialization of init_thread_local: {}", this.symbol));
+    }
+
+    /// Initializes the value of the current thread, returning `Err(value)` if it was already
+    /// initialized.
+    #[inline]
+    pub fn try_init(this: &Self, value: T) -> Result<(), T> {
+        this.inner.set(value)
+    }
+
+    /// Returns a reference to the value if it has been initialized on the current thread, or `None`
+    /// otherwise.
+    #[inline]
+    pub fn get(this: &Self) -> Option<&T> {
+        this.inner.get()
+    }
+
+    /// Returns whether the value has been initialized on the current thread.
+    #[inline]
+    pub fn is_set(this: &Self) -> bool {
+        this.inner.get().is_some()
+    }
+
+    /// Returns the [`Symbol`] associated with this thread-local, containing source location
+    /// metadata.
+    #[inline]
+    pub const fn symbol(this: &Self) -> &'static Symbol {
+        this.symbol
+    }
+}
+
+impl<T> Deref for InitThreadLocal<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.inner
+            .get()
+            .unwrap_or_else(|| panic!("access to uninitialized init_thread_local: {}", self.symbol))
+    }
+}
+
+impl<T: Debug> Debug for InitThreadLocal<T> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("InitThreadLocal").field(&**self).finish()
+    }
+}
+
+impl<T: Display> Display for InitThreadLocal<T> {
+    #[inline]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&**self, f)
+    }
+}
+
+impl<T> HasSymbol for InitThreadLocal<T> {
+    #[inline]
+    fn symbol(&self) -> &'static Symbol {
+        self.symbol
+    }
+}
+
+impl<T: 'static> MaybeInitStatic for LocalKey<InitThreadLocal<T>> {
+    #[inline]
+    fn __get_symbol(&self) -> Option<&'static Symbol> {
+        // SAFETY: `LocalKey` can only be constructed by `thread_local!`, which always declares it as
+        // a `static`.
+        let key = unsafe { &*(self as *const Self) };
+        Some(key.with(|value| value.symbol))
+    }
+}
@@ -1,16 +1,38 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(feature = "used_linker", feature(used_with_arg))]
 
+use std::any::Any;
+use std::cell::RefCell;
+#[cfg(feature = "async")]
+use std::collections::VecDeque;
 use std::collections::{HashMap, HashSet};
-use std::sync::Mutex;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+#[cfg(feature = "async")]
+use std::pin::pin;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::{Duration, Instant};
 
-use anyhow::{Context, Result};
-use futures_util::StreamExt;
+#[cfg(feature = "async")]
+use futures_timer::Delay;
+#[cfg(feature = "async")]
+use futures_util::future::{Either, select};
+#[cfg(feature = "async")]
 use futures_util::stream::FuturesUnordered;
+#[cfg(feature = "async")]
+use futures_util::{FutureExt, Stream, StreamExt};
 
-use crate::__private::{INIT, InitFn};
+use crate::__private::{CONST_SYMBOLS, DEINIT, DeinitFn, Init, InitFn, SYMBOLS, SyncInitFn, THREAD_LOCAL_INIT};
+use crate::error::{execution_error, panic_error};
+#[cfg(feature = "async")]
+use crate::event::EventQueue;
+use crate::init_static::INIT_OUTCOMES;
 
+mod builder;
 mod error;
+mod event;
 mod init_static;
+mod init_thread_local;
+mod report;
 
 /// Macro to declare statically stored values with explicit initialization. Similar to
 /// [`lazy_static!`](lazy_static::lazy_static!), but initialization is not automatic.
@@ -23,181 +45,2615 @@ mod init_static;
 ///
 /// The values are initialized when [`init_static()`] is called.
 ///
+/// To ease generating the block from other macros, a static may be terminated by a comma instead
+/// of a semicolon, the last static of the block may omit it, and items may be grouped in braces,
+/// which are expanded as if the braces were not there.
+///
+/// Attributes on a static, such as doc comments, are kept on the generated static. A
+/// `#[cfg(...)]` attribute also applies to its registration, so a static that is compiled out is
+/// not initialized. Its dependents may still reference it from code under the same `#[cfg(...)]`
+/// (such as a `let` statement or a `match` arm), in which case the dependency is only recorded when
+/// the predicate holds. Lints triggered by an init expression can be silenced with
+/// `#[init_allow(...)]`, which takes the same arguments as `#[allow(...)]` and applies them to the
+/// generated init function.
+///
+/// A static is initialized asynchronously if its init expression contains `.await`, in which case
+/// the resulting future must be [`Send`] so that it can be spawned with `set_spawner()`. Async
+/// statics require the `async` feature, which is enabled by default. Since an
+/// `.await` hidden inside a macro invocation cannot always be detected, async initialization can be
+/// forced with `#[init_async]`:
+///
+/// ```standalone_crate
+/// use init_static::init_static;
+///
+/// macro_rules! fetch {
+///     ($future:expr) => {
+///         $future.await
+///     };
+/// }
+///
+/// init_static! {
+///     #[init_async]
+///     static VALUE: u32 = fetch!(async { 42 });
+/// }
+/// ```
+///
+/// Several statics can be initialized by a single expression by destructuring a tuple. The
+/// expression runs once, and each static is initialized from the matching element:
+///
+/// ```
+/// use init_static::init_static;
+///
+/// fn connect() -> (String, u16) {
+///     ("localhost".to_owned(), 8080)
+/// }
+///
+/// init_static! {
+///     static (HOST, PORT): (String, u16) = connect();
+/// }
+/// ```
+///
+/// A fixed number of similar values, such as a set of workers, is declared as a single static of
+/// array type, e.g. built with [`std::array::from_fn`]. Every element is initialized after the
+/// dependencies of the array, and a static indexing into it depends on the whole array:
+///
+/// ```
+/// use init_static::init_static;
+///
+/// init_static! {
+///     static POOL: String = "pool".to_owned();
+///     static WORKERS: [String; 4] = std::array::from_fn(|i| format!("{}#{i}", *POOL));
+///     static PRIMARY: String = WORKERS[0].clone();
+/// }
+/// ```
+///
+/// The init function of a static is normally only run by [`init_static()`]. With `#[expose_init]`,
+/// it is also declared as `init_FOO()` next to the static, with the same visibility, so that a
+/// single static can be initialized on demand. It returns a [`Result`] (or an [`InitFuture`] for an
+/// async static) and does nothing if the static is already initialized, including when
+/// [`init_static()`] runs later. Its dependencies are not initialized by it, so they must be
+/// initialized first:
+///
+/// ```
+/// use init_static::init_static;
+///
+/// init_static! {
+///     #[expose_init]
+///     static SCHEMA: String = "CREATE TABLE users".to_owned();
+/// }
+///
+/// init_SCHEMA().unwrap();
+/// assert_eq!(*SCHEMA, "CREATE TABLE users");
+/// ```
+///
+/// A static initialized by a literal (or a tuple or array of literals), such as
+/// `static PORT: u16 = 8080;`, is initialized at compile time with [`InitStatic::with_value`]
+/// instead. It has no init function and is not part of the dependency graph, unless it is declared
+/// `static mut` or has an attribute such as `#[depends(...)]` or `#[deinit(...)]`. Such a static is
+/// not counted by [`registered_count()`] or [`registered_symbols()`], and does not appear in
+/// [`init_order()`] or [`dependency_graph()`]. Dependencies on it, including those declared with
+/// [`StaticBuilder::depends_on`], are always satisfied.
+///
+/// # Dependencies
+///
+/// Dependencies between statics are detected by scanning each init expression for `ALL_CAPS`
+/// paths. Macro invocations (such as `format!` or `vec!`) are scanned on a best-effort basis: their
+/// bodies are parsed as comma-separated expressions when possible, and otherwise searched for
+/// standalone `ALL_CAPS` identifiers. Paths in the declared type, such as an array length, are
+/// detected as well. When a dependency is not visible in the expression (e.g. it is accessed through a helper
+/// function), it can be declared explicitly with `#[depends(...)]`:
+///
+/// ```
+/// use init_static::init_static;
+///
+/// fn helper() -> u32 {
+///     *BASE + 1
+/// }
+///
+/// init_static! {
+///     static BASE: u32 = 42;
+///     #[depends(BASE)]
+///     static DERIVED: u32 = helper();
+/// }
+/// ```
+///
+/// Statics of every crate linked into the binary are collected together, so a static may depend on
+/// a static declared in another crate. Such a dependency is detected like any other when it is
+/// referenced by a path ending in an `ALL_CAPS` identifier, such as `other_crate::db::URL`, and can
+/// otherwise be declared by its full path in `#[depends(...)]`. The path may go through
+/// re-exports, since a dependency is identified by the [`Symbol`] of the static it resolves to
+/// rather than by the path naming it.
+///
+/// Dependencies are always initialized before their dependents, so an init expression may freely
+/// borrow them with `&*FOO` instead of copying or cloning their values. [`dep!`] does the same, but
+/// names the dependent static in its panic message should a dependency go undetected.
+///
+/// To also detect statics that are not named in `ALL_CAPS`, add `#![any_case_deps]` at the top of
+/// the block, or `#[any_case_deps]` on an individual static. Every snake_case path is then
+/// considered a potential dependency, which is harmless for values that are not statics.
+///
+/// ```
+/// # #![allow(non_upper_case_globals)]
+/// use init_static::init_static;
+///
+/// init_static! {
+///     #![any_case_deps]
+///     static base: u32 = 42;
+///     static derived: u32 = *base + 1;
+/// }
+/// ```
+///
+/// A static may also depend on a [`LazyLock`](std::sync::LazyLock) (or a `once_cell::sync::Lazy`
+/// with the `once_cell` feature). Such a dependency is forced right before the dependent static is
+/// initialized, so its own initializer runs in a predictable order rather than on first access.
+///
+/// ```
+/// use std::sync::LazyLock;
+///
+/// use init_static::init_static;
+///
+/// static CONFIG: LazyLock<u32> = LazyLock::new(|| 42);
+///
+/// init_static! {
+///     static VALUE: u32 = *CONFIG + 1;
+/// }
+/// ```
+///
+/// Conversely, falsely detected dependencies (such as `ALL_CAPS` constants) can be suppressed with
+/// `#[ignore_deps(...)]`, or all detected dependencies can be discarded with `#[no_deps]`. Paths
+/// listed in `#[depends(...)]` are always kept.
+///
+/// ```
+/// use init_static::init_static;
+///
+/// const MAX_RETRIES: u32 = 3;
+///
+/// init_static! {
+///     #[ignore_deps(MAX_RETRIES, u32::BITS)]
+///     static RETRIES: u32 = MAX_RETRIES * u32::BITS;
+/// }
+/// ```
+///
+/// A static that benefits from another being initialized first, but does not require it, can
+/// list it in `#[weak_depends(...)]`. The static is then ordered after it only if it is
+/// registered with `init_static`, and the dependency is silently dropped otherwise, regardless of
+/// [`set_missing_dep_policy()`]. A weak dependency is never forced, even if referenced by the
+/// expression.
+///
+/// ```
+/// use init_static::{InitStatic, init_static};
+///
+/// // Initialized manually, if at all.
+/// static CACHE: InitStatic<Vec<u32>> = InitStatic!(CACHE);
+///
+/// init_static! {
+///     #[weak_depends(CACHE)]
+///     static WARM: bool = InitStatic::is_set(&CACHE);
+/// }
+/// ```
+///
+/// When dependencies are only known at runtime (e.g. depending on a configuration flag), the
+/// dependency list can be computed by a function given via `#[deps_fn(...)]`. It is called when
+/// [`init_static()`] builds the graph, and its result replaces the dependencies detected in the
+/// expression or declared with `#[depends(...)]`.
+///
+/// ```
+/// use init_static::{InitStatic, Symbol, init_static};
+///
+/// fn cache_deps() -> Vec<Option<&'static Symbol>> {
+///     if std::env::var_os("USE_REDIS").is_some() {
+///         vec![Some(InitStatic::symbol(&REDIS))]
+///     } else {
+///         vec![]
+///     }
+/// }
+///
+/// init_static! {
+///     static REDIS: String = "redis://localhost".to_owned();
+///     #[deps_fn(cache_deps)]
+///     static CACHE: String = match std::env::var_os("USE_REDIS") {
+///         Some(_) => format!("cache({})", *REDIS),
+///         None => "memory".to_owned(),
+///     };
+/// }
+/// ```
+///
+/// # Context
+///
+/// A static annotated with `#[context(name: Type)]` borrows the context passed to
+/// [`init_static_with()`] as `name: &Type` in its init expression. See [`init_static_with()`] for
+/// an example.
+///
+/// # Example
+///
+/// ```
+/// use init_static::init_static;
+///
+/// init_static! {
+///     static VALUE: u32 = "42".parse()?;
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     init_static().await.unwrap();
+///     println!("{}", *VALUE);
+/// }
+/// ```
+pub use init_static_macro::init_static;
+/// Macro to declare thread-local values with explicit initialization.
+///
+/// This is the thread-local counterpart of [`init_static!`]. Each static declared using this macro
+/// is stored with [`thread_local!`], wrapped in [`InitThreadLocal`], and registered so that
+/// [`init_thread_local()`] initializes it on the calling thread. Dependencies between thread-locals
+/// are detected and honored in the same way as for [`init_static!`], and the values are accessed
+/// through [`LocalKey::with`](std::thread::LocalKey::with).
+///
+/// Thread-local initializers must be synchronous, and `#[deinit(...)]` is not supported.
+///
+/// # Example
+///
+/// ```
+/// use init_static::init_thread_local;
+///
+/// init_thread_local! {
+///     static CAPACITY: usize = 1024;
+///     static BUFFER: Vec<u8> = Vec::with_capacity(CAPACITY.with(|capacity| **capacity));
+/// }
+///
+/// fn main() {
+///     init_thread_local().unwrap();
+///     BUFFER.with(|buffer| assert!(buffer.capacity() >= 1024));
+///     std::thread::spawn(|| {
+///         init_thread_local().unwrap();
+///         BUFFER.with(|buffer| assert!(buffer.is_empty()));
+///     })
+///     .join()
+///     .unwrap();
+/// }
+/// ```
+pub use init_static_macro::init_thread_local;
+/// Attribute form of [`init_static!`] for a single static.
+///
+/// Annotating a static with `#[register]` is equivalent to declaring it inside an
+/// [`init_static!`] block, which lets statics stay in place next to the code and doc comments
+/// around them. The helper attributes described in [`init_static!`], such as `#[depends(...)]`, are
+/// supported on the annotated static.
+///
+/// # Example
+///
+/// ```
+/// use init_static::{init_static, register};
+///
+/// #[register]
+/// static VALUE: u32 = "42".parse()?;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     init_static().await.unwrap();
+///     println!("{}", *VALUE);
+/// }
+/// ```
+pub use init_static_macro::register;
+
+pub use crate::builder::{StaticBuilder, register_init};
+pub use crate::error::{Error, InitError, Result};
+pub use crate::event::InitEvent;
+pub use crate::init_static::{HasSymbol, InitState, InitStatic, Symbol, SymbolKey};
+pub use crate::init_thread_local::InitThreadLocal;
+pub use crate::report::{InitOutcome, InitReport};
+
+/// The future returned by the init function of an async static, as passed to the spawner set with
+/// [`set_spawner()`].
+#[cfg(feature = "async")]
+pub type InitFuture = __private::BoxFuture<Result<()>>;
+
+/// Every registered static: those declared with [`init_static!`], followed by those registered at
+/// runtime with [`StaticBuilder`]. Runtime registration is closed once this is first accessed.
+static INIT: LazyLock<Vec<Init>> =
+    LazyLock::new(|| __private::INIT.iter().copied().chain(builder::take_dynamic()).collect());
+
+struct InitOptions {
+    /// Whether debug output is enabled, or `None` if it has not been set, in which case it is read
+    /// from the environment when initialization starts. Other options left at `None` are read from
+    /// the environment in the same way.
+    debug: Option<bool>,
+    debug_prefix: &'static str,
+    #[cfg(feature = "async")]
+    max_concurrency: Option<usize>,
+    #[cfg(feature = "async")]
+    timeout: Option<Duration>,
+    #[cfg(feature = "async")]
+    total_timeout: Option<Duration>,
+    collect_errors: bool,
+    catch_panics: bool,
+    deterministic: bool,
+    #[cfg(feature = "async")]
+    prioritize_dependents: bool,
+    progress_hook: Option<ProgressHook>,
+    profile: bool,
+    retry: Option<(u32, Duration)>,
+    missing_deps: MissingDepPolicy,
+    #[cfg(feature = "async")]
+    spawner: Option<Spawner>,
+}
+
+type ProgressHook = Arc<dyn Fn(InitEvent) + Send + Sync>;
+
+/// The prefix of debug output, unless changed with [`set_debug_prefix()`].
+const DEFAULT_DEBUG_PREFIX: &str = "init_static:";
+
+#[cfg(feature = "async")]
+type Spawner = Box<dyn Fn(InitFuture) -> InitFuture + Send + Sync>;
+
+impl InitOptions {
+    const DEFAULT: Self = Self {
+        debug: None,
+        debug_prefix: DEFAULT_DEBUG_PREFIX,
+        #[cfg(feature = "async")]
+        max_concurrency: None,
+        #[cfg(feature = "async")]
+        timeout: None,
+        #[cfg(feature = "async")]
+        total_timeout: None,
+        collect_errors: false,
+        catch_panics: false,
+        deterministic: false,
+        #[cfg(feature = "async")]
+        prioritize_dependents: false,
+        progress_hook: None,
+        profile: false,
+        retry: None,
+        missing_deps: MissingDepPolicy::DEFAULT,
+        #[cfg(feature = "async")]
+        spawner: None,
+    };
+
+    /// Fills in the options that were not set explicitly from the `INIT_STATIC_*` environment
+    /// variables.
+    fn apply_env(&mut self) {
+        self.debug = self
+            .debug
+            .or_else(|| match std::env::var("INIT_STATIC_DEBUG").ok()?.as_str() {
+                "1" | "true" => Some(true),
+                "0" | "false" | "" => Some(false),
+                _ => None,
+            });
+        #[cfg(feature = "async")]
+        {
+            fn env_var<T: std::str::FromStr>(name: &str) -> Option<T> {
+                std::env::var(name).ok()?.parse().ok()
+            }
+            let env_millis = |name| env_var(name).map(Duration::from_millis);
+            self.max_concurrency = self
+                .max_concurrency
+                .or_else(|| env_var("INIT_STATIC_MAX_CONCURRENCY").filter(|&n| n > 0));
+            self.timeout = self.timeout.or_else(|| env_millis("INIT_STATIC_TIMEOUT_MS"));
+            self.total_timeout = self
+                .total_timeout
+                .or_else(|| env_millis("INIT_STATIC_TOTAL_TIMEOUT_MS"));
+        }
+    }
+
+    /// Runs a synchronous initializer, retrying it according to the retry policy and recording its
+    /// duration if profiling is enabled.
+    fn run_sync(&self, i: usize, f: &SyncInitFn) -> Result<()> {
+        let symbol = INIT[i].symbol;
+        let reporter = self.reporter(symbol);
+        self.emit(InitEvent::SyncBegin { symbol });
+        let start = self.profile.then(Instant::now);
+        let mut attempt = 1;
+        let output = loop {
+            let output = with_reporter(&reporter, || {
+                if self.catch_panics {
+                    catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| Err(panic_error(symbol, payload)))
+                } else {
+                    f()
+                }
+            });
+            match self.retry {
+                Some((max_attempts, backoff)) if output.is_err() && attempt < max_attempts => {
+                    attempt += 1;
+                    std::thread::sleep(backoff);
+                }
+                _ => break output,
+            }
+        };
+        if let Some(start) = start {
+            INIT_TIMINGS.lock().unwrap().push((symbol, start.elapsed()));
+        }
+        self.emit(InitEvent::SyncEnd { symbol });
+        output
+    }
+
+    /// Runs an asynchronous initializer, applying the timeout to each attempt, retrying it
+    /// according to the retry policy and recording its duration if profiling is enabled.
+    #[cfg(feature = "async")]
+    async fn run_async(&self, i: usize) -> (usize, Result<()>) {
+        let InitFn::Async(f) = &INIT[i].init else {
+            unreachable!();
+        };
+        let symbol = INIT[i].symbol;
+        self.emit(InitEvent::AsyncBegin { symbol });
+        let start = Instant::now();
+        let mut attempt = 1;
+        let output = loop {
+            let attempt_start = Instant::now();
+            let mut future = if self.catch_panics {
+                // The init function is called inside the future, so that panics raised before its
+                // first `.await` are caught as well.
+                let future = AssertUnwindSafe(async { f().await }).catch_unwind();
+                Box::pin(future.map(move |output| output.unwrap_or_else(|payload| Err(panic_error(symbol, payload)))))
+            } else {
+                f()
+            };
+            let reporter = self.reporter(symbol);
+            let future: InitFuture = Box::pin(std::future::poll_fn(move |cx| {
+                with_reporter(&reporter, || future.as_mut().poll(cx))
+            }));
+            let future = match &self.spawner {
+                Some(spawner) => spawner(future),
+                None => future,
+            };
+            let output = match self.timeout {
+                Some(timeout) => match select(future, Delay::new(timeout)).await {
+                    Either::Left((output, _)) => output,
+                    Either::Right(_) => Err(InitError::Timeout {
+                        symbol,
+                        elapsed: attempt_start.elapsed(),
+                    }
+                    .into()),
+                },
+                None => future.await,
+            };
+            match self.retry {
+                Some((max_attempts, backoff)) if output.is_err() && attempt < max_attempts => {
+                    attempt += 1;
+                    Delay::new(backoff).await;
+                }
+                _ => break output,
+            }
+        };
+        if self.profile {
+            INIT_TIMINGS.lock().unwrap().push((symbol, start.elapsed()));
+        }
+        self.emit(InitEvent::AsyncEnd { symbol });
+        (i, output)
+    }
+
+    /// Warns about the dependencies of the statics about to be initialized that are not managed by
+    /// `init_static`, as their initialization is not ordered. See [`unmanaged_dependencies()`].
+    fn warn_unmanaged(&self, graph: &Graph) {
+        #[cfg(not(feature = "tracing"))]
+        if self.debug != Some(true) {
+            return;
+        }
+        let mut indices = graph.deps.keys().copied().collect::<Vec<_>>();
+        indices.sort_unstable();
+        for i in indices {
+            let symbol = INIT[i].symbol;
+            for path in (INIT[i].unmanaged)() {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(
+                    ident = symbol.ident,
+                    module = symbol.module,
+                    dependency = path,
+                    "dependency not managed by init_static",
+                );
+                #[cfg(not(feature = "tracing"))]
+                eprintln!(
+                    "{} warning: {symbol} depends on `{path}`, which is not managed by init_static",
+                    self.debug_prefix
+                );
+            }
+        }
+    }
+
+    /// Notes the async statics about to be initialized that have no dependencies, which are async
+    /// only because of futures unrelated to other statics. They could often be made synchronous,
+    /// or started earlier by moving their awaited work elsewhere.
+    #[cfg(feature = "async")]
+    fn note_async_without_deps(&self, graph: &Graph) {
+        #[cfg(not(feature = "tracing"))]
+        if self.debug != Some(true) {
+            return;
+        }
+        let mut indices = graph
+            .deps
+            .iter()
+            .filter(|(_, deps)| deps.is_empty())
+            .map(|(&i, _)| i)
+            .collect::<Vec<_>>();
+        indices.sort_unstable();
+        for i in indices {
+            if !matches!(INIT[i].init, InitFn::Async(_)) {
+                continue;
+            }
+            let symbol = INIT[i].symbol;
+            #[cfg(feature = "tracing")]
+            tracing::debug!(
+                ident = symbol.ident,
+                module = symbol.module,
+                "async static has no dependencies",
+            );
+            #[cfg(not(feature = "tracing"))]
+            eprintln!("{} note: async static {symbol} has no dependencies", self.debug_prefix);
+        }
+    }
+
+    /// Returns the prefix of debug output if debug mode is enabled, or `None` otherwise.
+    fn debug(&self) -> Option<&'static str> {
+        (self.debug == Some(true)).then_some(self.debug_prefix)
+    }
+
+    fn emit(&self, event: InitEvent) {
+        emit_event(self.debug(), self.progress_hook.as_ref(), event);
+    }
+
+    /// Returns the reporter through which the initializer of `symbol` reports its progress.
+    fn reporter(&self, symbol: &'static Symbol) -> ProgressReporter {
+        ProgressReporter {
+            symbol,
+            debug: self.debug(),
+            hook: self.progress_hook.clone(),
+        }
+    }
+}
+
+#[cfg_attr(feature = "tracing", allow(unused_variables))]
+fn emit_event(debug: Option<&str>, hook: Option<&ProgressHook>, event: InitEvent) {
+    #[cfg(feature = "tracing")]
+    trace_event(event);
+    #[cfg(not(feature = "tracing"))]
+    if let Some(prefix) = debug {
+        match event {
+            InitEvent::SyncBegin { symbol } => eprintln!("{prefix} sync {symbol}"),
+            InitEvent::AsyncBegin { symbol } => eprintln!("{prefix} async begin {symbol}"),
+            InitEvent::AsyncEnd { symbol } => eprintln!("{prefix} async end {symbol}"),
+            InitEvent::Progress { symbol, progress } => {
+                eprintln!("{prefix} progress {symbol} {:.0}%", progress * 100.0)
+            }
+            _ => {}
+        }
+    }
+    if let Some(hook) = hook {
+        hook(event);
+    }
+}
+
+/// The destination of the progress reported by a running initializer. See [`report_progress()`].
+#[derive(Clone)]
+struct ProgressReporter {
+    symbol: &'static Symbol,
+    debug: Option<&'static str>,
+    hook: Option<ProgressHook>,
+}
+
+thread_local! {
+    /// The reporter of the initializer being run or polled on the current thread.
+    static CURRENT_REPORTER: RefCell<Option<ProgressReporter>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `reporter` as the reporter of the current thread, restoring the previous one
+/// afterwards, even if `f` panics.
+fn with_reporter<R>(reporter: &ProgressReporter, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<ProgressReporter>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CURRENT_REPORTER.with(|current| *current.borrow_mut() = self.0.take());
+        }
+    }
+
+    let _restore = Restore(CURRENT_REPORTER.with(|current| current.replace(Some(reporter.clone()))));
+    f()
+}
+
+#[cfg(feature = "tracing")]
+fn trace_event(event: InitEvent) {
+    macro_rules! trace_symbol {
+        ($symbol:expr, $($field:ident,)* $message:literal) => {
+            tracing::debug!(
+                ident = $symbol.ident,
+                module = $symbol.module,
+                file = $symbol.file,
+                line = $symbol.line,
+                $($field,)*
+                $message,
+            )
+        };
+    }
+    match event {
+        InitEvent::Started { total } => tracing::info!(total, "init_static started"),
+        InitEvent::SyncBegin { symbol } => trace_symbol!(symbol, "sync begin"),
+        InitEvent::SyncEnd { symbol } => trace_symbol!(symbol, "sync end"),
+        InitEvent::AsyncBegin { symbol } => trace_symbol!(symbol, "async begin"),
+        InitEvent::AsyncEnd { symbol } => trace_symbol!(symbol, "async end"),
+        InitEvent::Progress { symbol, progress } => trace_symbol!(symbol, progress, "progress"),
+        InitEvent::Finished => tracing::info!("init_static finished"),
+    }
+}
+
+fn update_options(f: impl FnOnce(&mut InitOptions)) {
+    f(INIT_OPTIONS
+        .lock()
+        .unwrap()
+        .as_mut()
+        .expect("INIT_OPTIONS can only be modified before `init_static` is called."))
+}
+
+static INIT_OPTIONS: Mutex<Option<InitOptions>> = Mutex::new(Some(InitOptions::DEFAULT));
+
+/// Indices into [`INIT`] of the statics that have been initialized successfully, in completion
+/// order. They are skipped when initialization is retried after a failure, and torn down in reverse
+/// order by [`deinit_static()`].
+static INIT_COMPLETED: Mutex<Vec<usize>> = Mutex::new(Vec::new());
+
+static INIT_TIMINGS: Mutex<Vec<(&'static Symbol, Duration)>> = Mutex::new(Vec::new());
+
+/// Enables or disables debug output during initialization.
+///
+/// When debug mode is enabled, the initialization process prints messages
+/// to stderr indicating:
+///
+/// - When each synchronous static is initialized
+/// - When each asynchronous static begins and completes initialization
+/// - Which dependencies are not managed by `init_static` (see [`unmanaged_dependencies()`])
+/// - Which async statics have no dependencies, and are therefore async only because of futures
+///   unrelated to other statics
+///
+/// This is useful for diagnosing initialization order issues or performance
+/// problems during startup.
+///
+/// With the `tracing` feature enabled, these messages are always emitted as `tracing` events
+/// (filtered by the installed subscriber) instead of being printed to stderr, and this setting has
+/// no effect. Each async static is also initialized within its own span.
+///
+/// If this is never called, debug mode is read from the `INIT_STATIC_DEBUG` environment variable
+/// (`1` or `true` to enable) when initialization starts.
+pub fn set_debug(debug: bool) {
+    update_options(|options| options.debug = Some(debug));
+}
+
+/// Sets the prefix of the lines printed to stderr in debug mode (see [`set_debug()`]), e.g. to
+/// tell them apart from other startup output. Defaults to `init_static:`.
+///
+/// This has no effect with the `tracing` feature, whose events are labeled by their target.
+pub fn set_debug_prefix(prefix: &'static str) {
+    update_options(|options| options.debug_prefix = prefix);
+}
+
+/// Limits the number of asynchronous statics that are initialized concurrently.
+///
+/// By default, every async static whose dependencies are satisfied is polled at the same time.
+/// With a limit of `n`, at most `n` async initializers run simultaneously and the rest are queued
+/// in the order they become ready. This is useful for statics that establish connection pools or
+/// otherwise consume limited resources during startup.
+///
+/// If this is never called, the limit is read from the `INIT_STATIC_MAX_CONCURRENCY` environment
+/// variable when initialization starts.
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+#[cfg(feature = "async")]
+pub fn set_max_concurrency(n: usize) {
+    assert!(n > 0, "max concurrency must be greater than zero");
+    update_options(|options| options.max_concurrency = Some(n));
+}
+
+/// Sets the maximum duration each asynchronous static may take to initialize.
+///
+/// If an async initializer does not complete in time, [`init_static()`] fails with
+/// [`InitError::Timeout`]. Synchronous statics run to completion on the calling thread and cannot
+/// be timed out.
+///
+/// If this is never called, the timeout is read in milliseconds from the `INIT_STATIC_TIMEOUT_MS`
+/// environment variable when initialization starts.
+#[cfg(feature = "async")]
+pub fn set_timeout(timeout: Duration) {
+    update_options(|options| options.timeout = Some(timeout));
+}
+
+/// Sets the maximum duration of the whole initialization process.
+///
+/// If [`init_static()`] does not complete in time, it fails with [`InitError::TotalTimeout`]. The
+/// budget is only checked while waiting on asynchronous statics, so a long-running synchronous
+/// initializer may overrun it.
+///
+/// If this is never called, the timeout is read in milliseconds from the
+/// `INIT_STATIC_TOTAL_TIMEOUT_MS` environment variable when initialization starts.
+#[cfg(feature = "async")]
+pub fn set_total_timeout(timeout: Duration) {
+    update_options(|options| options.total_timeout = Some(timeout));
+}
+
+/// Enables or disables collecting all initialization errors instead of failing fast.
+///
+/// By default, [`init_static()`] returns as soon as any static fails to initialize. When this is
+/// enabled, it keeps initializing every static whose dependencies succeeded, and finally returns
+/// [`InitError::Multiple`] listing all failures along with the statics that were skipped because
+/// one of their dependencies failed, so that the reach of each failure can be seen at once.
+pub fn set_collect_errors(collect_errors: bool) {
+    update_options(|options| options.collect_errors = collect_errors);
+}
+
+/// Enables or disables catching panics raised by init functions.
+///
+/// By default, a panicking initializer unwinds out of [`init_static()`]. When this is enabled, the
+/// panic is caught and reported as [`InitError::Panic`], naming the static that panicked, so that
+/// it is handled like any other initialization error (e.g. retried or collected). This has no
+/// effect if panics abort the process.
+pub fn set_catch_panics(catch_panics: bool) {
+    update_options(|options| options.catch_panics = catch_panics);
+}
+
+/// Enables or disables deterministic initialization order.
+///
+/// By default, statics whose dependencies are satisfied run in registration order, which is
+/// determined by the linker and may vary across platforms, and async statics complete in arbitrary
+/// order. When this is enabled, ready statics are sorted by their source location (file, line and
+/// column), and async statics are awaited one at a time in that order. This trades parallelism for
+/// reproducible logs and test assertions.
+pub fn set_deterministic(deterministic: bool) {
+    update_options(|options| options.deterministic = deterministic);
+}
+
+/// Sets a function building the panic message for an access to an uninitialized
+/// [`struct@InitStatic`], e.g. to add a hint specific to the application.
+///
+/// By default, the message names the static and hints at initializing it first. The hook is not
+/// consulted for statics whose initializer failed, which report the error instead. Unlike the other
+/// options, this may be set at any time, including after [`init_static()`] has been called.
+///
+/// # Example
+///
+/// ```should_panic
+/// use init_static::{InitStatic, set_uninit_hook};
+///
+/// static CONFIG: InitStatic<String> = InitStatic!(CONFIG);
+///
+/// set_uninit_hook(|symbol| format!("{} is not loaded, run `app init` first", symbol.ident));
+/// println!("{}", *CONFIG);
+/// ```
+pub fn set_uninit_hook(hook: fn(&'static Symbol) -> String) {
+    *crate::init_static::UNINIT_HOOK.lock().unwrap() = Some(hook);
+}
+
+/// Enables or disables prioritizing the async statics that the most statics depend on.
+///
+/// By default, ready async statics are started in the order they became ready. When this is
+/// enabled, those with the most direct dependents are started first, which shortens the critical
+/// path when only a few can run at once, e.g. with [`set_max_concurrency()`]. Statics with the
+/// same number of dependents keep their usual order.
+#[cfg(feature = "async")]
+pub fn set_prioritize_dependents(prioritize_dependents: bool) {
+    update_options(|options| options.prioritize_dependents = prioritize_dependents);
+}
+
+/// Sets a callback that observes initialization progress.
+///
+/// The hook is called with an [`InitEvent`] when initialization starts, before and after each
+/// static is initialized, and when initialization finishes. This is useful for rendering progress
+/// indicators keyed on the total number of statics. The hook is called in addition to the stderr
+/// output enabled by [`set_debug()`].
+///
+/// Initializers may also report intermediate progress with [`report_progress()`].
+pub fn set_progress_hook(hook: impl Fn(InitEvent) + Send + Sync + 'static) {
+    update_options(|options| options.progress_hook = Some(Arc::new(hook)));
+}
+
+/// Reports the progress of the initializer currently running, as an [`InitEvent::Progress`] passed
+/// to the hook set by [`set_progress_hook()`].
+///
+/// This is meant for long-running initializers, such as one loading a large file, to report more
+/// than the begin and end events. `progress` is conventionally between `0.0` and `1.0`. It may be
+/// called from the init expression itself or from any function it calls, as long as it runs on the
+/// thread (or, for async statics, in the future) of the initializer. Calls made elsewhere, such as
+/// from a task spawned by the initializer, are ignored.
+///
+/// # Example
+///
+/// ```standalone_crate
+/// use init_static::{InitEvent, init_static, report_progress, set_progress_hook};
+///
+/// fn load_model() -> Vec<u8> {
+///     let mut model = Vec::new();
+///     for chunk in 0..4 {
+///         model.push(chunk);
+///         report_progress((chunk + 1) as f64 / 4.0);
+///     }
+///     model
+/// }
+///
+/// init_static! {
+///     static MODEL: Vec<u8> = load_model();
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     set_progress_hook(|event| {
+///         if let InitEvent::Progress { symbol, progress } = event {
+///             println!("{}: {:.0}%", symbol.ident, progress * 100.0);
+///         }
+///     });
+///     init_static().await.unwrap();
+/// }
+/// ```
+pub fn report_progress(progress: f64) {
+    let Some(reporter) = CURRENT_REPORTER.with(|current| current.borrow().clone()) else {
+        return;
+    };
+    emit_event(
+        reporter.debug,
+        reporter.hook.as_ref(),
+        InitEvent::Progress {
+            symbol: reporter.symbol,
+            progress,
+        },
+    );
+}
+
+/// Sets a function that runs the init futures of async statics, e.g. on an async runtime's thread
+/// pool.
+///
+/// By default, async statics are polled inline by the future returned from [`init_static()`], so
+/// they all run on the calling task. The spawner receives the init future of each async static
+/// (once per attempt if a retry policy is set) and returns a future that completes with its output,
+/// which allows CPU-bound initializers to run in parallel. The timeout set with [`set_timeout()`]
+/// only stops waiting for a spawned future; it is not cancelled unless the spawner does so.
+///
+/// # Example
+///
+/// ```
+/// use init_static::{init_static, set_spawner};
+///
+/// init_static! {
+///     static VALUE: u32 = async { 42 }.await;
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     set_spawner(|future| Box::pin(async { tokio::spawn(future).await? }));
+///     init_static().await.unwrap();
+///     println!("{}", *VALUE);
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub fn set_spawner(spawner: impl Fn(InitFuture) -> InitFuture + Send + Sync + 'static) {
+    update_options(|options| options.spawner = Some(Box::new(spawner)));
+}
+
+/// Sets a retry policy for statics whose initializer returns an error.
+///
+/// A failing initializer is attempted up to `max_attempts` times in total, waiting `backoff`
+/// between attempts. If every attempt fails, the error of the last attempt is reported. When a
+/// timeout is set with [`set_timeout()`], it applies to each attempt individually. This is mostly
+/// useful for async statics that connect to external services; synchronous statics block the
+/// calling thread while waiting between attempts.
+///
+/// # Panics
+///
+/// Panics if `max_attempts` is zero.
+pub fn set_retry(max_attempts: u32, backoff: Duration) {
+    assert!(max_attempts > 0, "max attempts must be greater than zero");
+    update_options(|options| options.retry = Some((max_attempts, backoff)));
+}
+
+/// What [`init_static()`] does with a dependency on an [`struct@InitStatic`] that is not registered
+/// with [`init_static!`]. See [`set_missing_dep_policy()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingDepPolicy {
+    /// The dependency is silently dropped.
+    Ignore,
+    /// The dependency is dropped with a warning, printed to stderr or emitted with `tracing` when
+    /// the `tracing` feature is enabled.
+    Warn,
+    /// Initialization fails with [`InitError::MissingDependency`] before any initializer runs.
+    Error,
+}
+
+impl MissingDepPolicy {
+    const DEFAULT: Self = if cfg!(debug_assertions) {
+        Self::Warn
+    } else {
+        Self::Ignore
+    };
+}
+
+impl Default for MissingDepPolicy {
+    /// Returns [`MissingDepPolicy::Warn`] in debug builds and [`MissingDepPolicy::Ignore`] in
+    /// release builds.
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Sets the policy for dependencies on unregistered statics.
+///
+/// A dependency on an [`struct@InitStatic`] that is not registered with [`init_static!`] is
+/// dropped from the graph, since the value may be initialized manually with [`InitStatic::init`].
+/// A dependency removed from the registry (e.g. by conditional compilation) may then go unnoticed
+/// and lead to a wrong initialization order. This makes it possible to, for example, fail startup
+/// in CI while staying tolerant in production.
+///
+/// Defaults to [`MissingDepPolicy::Warn`] in debug builds and [`MissingDepPolicy::Ignore`] in
+/// release builds. References to values that are not [`struct@InitStatic`] at all are never
+/// reported.
+pub fn set_missing_dep_policy(policy: MissingDepPolicy) {
+    update_options(|options| options.missing_deps = policy);
+}
+
+/// Enables or disables strict dependency validation.
+///
+/// This is a shorthand for [`set_missing_dep_policy()`] with [`MissingDepPolicy::Error`], or with
+/// the default policy when disabled.
+pub fn set_strict_deps(strict_deps: bool) {
+    set_missing_dep_policy(if strict_deps {
+        MissingDepPolicy::Error
+    } else {
+        MissingDepPolicy::default()
+    });
+}
+
+/// Enables or disables recording how long each static takes to initialize.
+///
+/// The recorded durations are available via [`last_init_report()`]. Profiling is disabled by
+/// default so that no timing overhead is incurred.
+pub fn set_profile(profile: bool) {
+    update_options(|options| options.profile = profile);
+}
+
+/// Returns how long each static took to initialize during the last call to [`init_static()`].
+///
+/// Entries are listed in completion order. The report is empty unless profiling was enabled with
+/// [`set_profile()`]. For async statics, the duration covers the time from the first poll to
+/// completion, which includes time spent waiting on other concurrently running statics.
+///
+/// # Example
+///
+/// ```
+/// use init_static::{init_static, last_init_report, set_profile};
+///
+/// init_static! {
+///     static VALUE: u32 = "42".parse()?;
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     set_profile(true);
+///     init_static().await.unwrap();
+///     let mut report = last_init_report();
+///     report.sort_by_key(|(_, duration)| std::cmp::Reverse(*duration));
+///     for (symbol, duration) in report.iter().take(10) {
+///         println!("{symbol}: {duration:?}");
+///     }
+/// }
+/// ```
+pub fn last_init_report() -> Vec<(&'static Symbol, Duration)> {
+    INIT_TIMINGS.lock().unwrap().clone()
+}
+
+/// Returns the outcome of initializing `symbol` with [`init_static()`], or `None` if it has not
+/// been processed, e.g. because initialization has not run yet or was aborted by an earlier error.
+///
+/// This is most useful with [`set_collect_errors()`], to report which subsystems came up, e.g.
+/// from a health endpoint. Statics initialized by hand with [`InitStatic::init`] have no outcome.
+///
+/// # Example
+///
+/// ```standalone_crate
+/// use init_static::{InitOutcome, InitStatic, init_outcome, init_static, set_collect_errors};
+///
+/// init_static! {
+///     static DB: u32 = "db".parse()?;
+///     static CACHE: u32 = "42".parse()?;
+///     static POOL: u32 = *DB + 1;
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     set_collect_errors(true);
+///     init_static().await.unwrap_err();
+///     assert_eq!(init_outcome(InitStatic::symbol(&CACHE)), Some(InitOutcome::Ok));
+///     assert!(matches!(init_outcome(InitStatic::symbol(&DB)), Some(InitOutcome::Failed(_))));
+///     assert_eq!(
+///         init_outcome(InitStatic::symbol(&POOL)),
+///         Some(InitOutcome::Skipped(InitStatic::symbol(&DB))),
+///     );
+/// }
+/// ```
+pub fn init_outcome(symbol: &'static Symbol) -> Option<InitOutcome> {
+    INIT_OUTCOMES.lock().unwrap().get(&symbol.key()).cloned()
+}
+
+/// Returns whether [`init_static()`] has already been called.
+///
+/// This function checks if the initialization process has been executed. It returns `true` if
+/// [`init_static()`] is running or has succeeded, and `false` otherwise. Since a failed
+/// initialization can be retried, it returns `false` again after [`init_static()`] fails.
+pub fn is_initialized() -> bool {
+    INIT_OPTIONS.lock().unwrap().is_none()
+}
+
+/// Returns the initialization order computed from the dependency graph, without running any
+/// initializer.
+///
+/// Each inner `Vec` is a layer of statics whose dependencies are all in earlier layers, sorted in
+/// source order; flattening the result yields a valid topological order. Since the output does not
+/// depend on registration order, it can be printed and diffed across builds to catch accidental
+/// dependency changes.
+///
+/// The order describes the full graph and ignores whether statics have already been initialized.
+/// At runtime, the async driver may start a static as soon as its own dependencies are done, so
+/// statics of adjacent layers can overlap.
+///
+/// Returns the same errors as [`init_static()`] for ambiguous or circular dependencies.
+///
+/// # Example
+///
+/// ```
+/// use init_static::init_order;
+///
+/// init_static::init_static! {
+///     static URL: String = "postgres://localhost".to_owned();
+///     static POOL: String = format!("pool({})", *URL);
+/// }
+///
+/// for (layer, symbols) in init_order().unwrap().iter().enumerate() {
+///     for symbol in symbols {
+///         println!("{layer}: {symbol}");
+///     }
+/// }
+/// ```
+pub fn init_order() -> Result<Vec<Vec<&'static Symbol>>> {
+    let layers = compute_layers(MissingDepPolicy::Ignore, DEFAULT_DEBUG_PREFIX)?;
+    Ok(layers
+        .into_iter()
+        .map(|layer| layer.into_iter().map(|i| INIT[i].symbol).collect())
+        .collect())
+}
+
+/// Checks that the dependency graph can be initialized, without running any initializer.
+///
+/// The graph is built exactly as [`init_static()`] would build it, honoring
+/// [`set_missing_dep_policy()`], and checked for ambiguous, missing, self and circular dependencies.
+/// Since no init expression is evaluated, this is suitable for a fast test asserting the health of
+/// the graph, even when initializers contact external services.
+///
+/// # Example
+///
+/// ```standalone_crate
+/// use init_static::{init_static, validate_graph};
+///
+/// init_static! {
+///     static URL: String = std::env::var("DATABASE_URL")?;
+///     static POOL: String = format!("pool({})", *URL);
+/// }
+///
+/// fn main() {
+///     validate_graph().unwrap();
+/// }
+/// ```
+pub fn validate_graph() -> std::result::Result<(), InitError> {
+    let (missing_deps, debug_prefix) = INIT_OPTIONS
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map_or((MissingDepPolicy::DEFAULT, DEFAULT_DEBUG_PREFIX), |options| {
+            (options.missing_deps, options.debug_prefix)
+        });
+    compute_layers(missing_deps, debug_prefix)?;
+    Ok(())
+}
+
+/// Returns the number of statics registered for initialization.
+///
+/// This counts the statics declared with [`init_static!`] and those registered with
+/// [`StaticBuilder`], which can no longer be registered afterwards. Statics initialized at compile
+/// time (see [`init_static!`]) are not counted. This is useful for asserting in a test that no
+/// static was added or removed by accident.
+///
+/// # Example
+///
+/// ```standalone_crate
+/// use init_static::{init_static, registered_count};
+///
+/// init_static! {
+///     static URL: String = "postgres://localhost".to_owned();
+///     static POOL: String = format!("pool({})", *URL);
+/// }
+///
+/// fn main() {
+///     assert_eq!(registered_count(), 2);
+/// }
+/// ```
+pub fn registered_count() -> usize {
+    INIT.len()
+}
+
+/// Returns the symbols of the statics registered for initialization, in registry order.
+///
+/// See [`registered_count()`] for which statics are included.
+///
+/// # Example
+///
+/// ```standalone_crate
+/// use init_static::{init_static, registered_symbols};
+///
+/// init_static! {
+///     static URL: String = "postgres://localhost".to_owned();
+/// }
+///
+/// fn main() {
+///     let idents = registered_symbols().map(|symbol| symbol.ident).collect::<Vec<_>>();
+///     assert_eq!(idents, ["URL"]);
+/// }
+/// ```
+pub fn registered_symbols() -> impl Iterator<Item = &'static Symbol> {
+    INIT.iter().map(|init| init.symbol)
+}
+
+/// Returns the dependency graph of all statics declared with [`init_static!`], without running any
+/// initializer.
+///
+/// Each entry pairs a static with the statics it depends on, resolved as [`init_static()`] would.
+/// Both are sorted in source order, and dependencies that are not statics are dropped. See
+/// [`dependency_graph_dot()`] for rendering the graph with Graphviz.
+///
+/// This is also the basis for custom checks on the graph, such as forbidding the statics of one
+/// module from depending on those of another.
+///
+/// # Example
+///
+/// ```standalone_crate
+/// use init_static::{dependency_graph, init_static};
+///
+/// mod db {
+///     init_static::init_static! {
+///         pub static URL: String = "postgres://localhost".to_owned();
+///     }
+/// }
+///
+/// init_static! {
+///     static POOL: String = format!("pool({})", *db::URL);
+/// }
+///
+/// fn main() {
+///     let graph = dependency_graph();
+///     assert_eq!(graph[1].0.ident, "POOL");
+///     assert_eq!(graph[1].1[0].ident, "URL");
+///
+///     // No static in `db` may depend on a static outside of it.
+///     for (symbol, deps) in &graph {
+///         if symbol.module.ends_with("::db") {
+///             assert!(deps.iter().all(|dep| dep.module == symbol.module));
+///         }
+///     }
+/// }
+/// ```
+pub fn dependency_graph() -> Vec<(&'static Symbol, Vec<&'static Symbol>)> {
+    let registered = INIT.iter().map(|init| init.symbol.key()).collect::<HashSet<_>>();
+    let mut graph = INIT
+        .iter()
+        .map(|init| {
+            let mut deps = init.deps.symbols().collect::<Vec<_>>();
+            let weak_deps = init.weak_deps.iter().filter_map(|f| f());
+            deps.extend(weak_deps.filter(|symbol| registered.contains(&symbol.key())));
+            deps.sort();
+            deps.dedup_by_key(|symbol| symbol.key());
+            (init.symbol, deps)
+        })
+        .collect::<Vec<_>>();
+    graph.sort_by_key(|(symbol, _)| *symbol);
+    graph
+}
+
+/// Returns the paths referenced by init expressions that look like statics but are not managed by
+/// `init_static`, without running any initializer.
+///
+/// Such a path is neither a static declared with [`init_static!`] (or [`InitStatic!`]) nor a
+/// recognized lazy type such as [`LazyLock`](std::sync::LazyLock), so no ordering is enforced
+/// for it: a plain `static` or `lazy_static!` may still be uninitialized when the dependent runs.
+/// Constants are reported as well, and can be silenced with `#[ignore_deps(...)]`. The same
+/// paths are reported as warnings when [`init_static()`] runs with [`set_debug()`] enabled (or
+/// with the `tracing` feature).
+///
+/// The paths are only recorded in debug builds (with `debug_assertions`) of the crate declaring the
+/// statics, so this returns nothing for statics declared in a release build.
+///
+/// # Example
+///
+/// ```standalone_crate
+/// use init_static::{init_static, unmanaged_dependencies};
+///
+/// static PORT: u16 = 8080;
+///
+/// init_static! {
+///     static ADDR: String = format!("localhost:{}", PORT);
+/// }
+///
+/// fn main() {
+///     let unmanaged = unmanaged_dependencies();
+///     assert_eq!(unmanaged[0].0.ident, "ADDR");
+///     assert_eq!(unmanaged[0].1, "PORT");
+/// }
+/// ```
+pub fn unmanaged_dependencies() -> Vec<(&'static Symbol, &'static str)> {
+    let mut unmanaged = INIT
+        .iter()
+        .flat_map(|init| (init.unmanaged)().into_iter().map(|path| (init.symbol, path)))
+        .collect::<Vec<_>>();
+    unmanaged.sort();
+    unmanaged
+}
+
+/// Renders [`dependency_graph()`] in the Graphviz `dot` language, with an edge pointing from each
+/// static to each of its dependencies.
+///
+/// # Example
+///
+/// ```standalone_crate
+/// use init_static::{dependency_graph_dot, init_static};
+///
+/// init_static! {
+///     static URL: String = "postgres://localhost".to_owned();
+///     static POOL: String = format!("pool({})", *URL);
+/// }
+///
+/// fn main() {
+///     let dot = dependency_graph_dot();
+///     assert!(dot.starts_with("digraph init_static {"));
+///     assert!(dot.contains("::POOL\" -> \""));
+/// }
+/// ```
+pub fn dependency_graph_dot() -> String {
+    let node = |symbol: &Symbol| format!("\"{}::{}\"", symbol.module, symbol.ident);
+    let mut dot = String::from("digraph init_static {\n");
+    for (symbol, deps) in dependency_graph() {
+        dot += &format!("    {};\n", node(symbol));
+        for dep in deps {
+            dot += &format!("    {} -> {};\n", node(symbol), node(dep));
+        }
+    }
+    dot += "}\n";
+    dot
+}
+
+/// Splits the full graph of [`INIT`] into layers of indices in source order, as described in
+/// [`init_order()`].
+fn compute_layers(
+    missing_deps: MissingDepPolicy,
+    debug_prefix: &str,
+) -> std::result::Result<Vec<Vec<usize>>, InitError> {
+    let mut graph = Graph::new(build_graph(&INIT, missing_deps, debug_prefix)?);
+    let mut layers = vec![];
+    while !graph.is_empty() {
+        let layer = graph.take_layer(&INIT, true);
+        if layer.is_empty() {
+            return Err(graph.circular_error(&INIT));
+        }
+        for &i in &layer {
+            graph.complete(i);
+        }
+        layers.push(layer);
+    }
+    Ok(layers)
+}
+
+/// Restores the initialization options to their defaults, allowing [`init_static()`] to be called
+/// again.
+///
+/// The state recorded by the previous run is cleared as well: the outcomes returned by
+/// [`init_outcome()`], the durations returned by [`last_init_report()`], and the set of statics
+/// considered initialized by [`verify_all_initialized()`].
+///
+/// This is intended for test harnesses that need to exercise the initialization flow more than
+/// once in the same process. Statics that were already initialized must be cleared with
+/// [`InitStatic::reset`] before re-running [`init_static()`], otherwise it will fail with a double
+/// initialization panic.
+#[cfg(feature = "reset")]
+pub fn reset_init_options() {
+    *INIT_OPTIONS.lock().unwrap() = Some(InitOptions::DEFAULT);
+    INIT_COMPLETED.lock().unwrap().clear();
+    INIT_OUTCOMES.lock().unwrap().clear();
+    INIT_TIMINGS.lock().unwrap().clear();
+    crate::init_static::INITIALIZED_SYMBOLS.lock().unwrap().clear();
+}
+
+fn take_options() -> InitOptions {
+    let mut options = INIT_OPTIONS
+        .lock()
+        .unwrap()
+        .take()
+        .expect("`init_static` can only be called once.");
+    options.apply_env();
+    options
+}
+
+/// Puts the options back after a failed initialization so that it can be retried.
+fn restore_on_error(options: InitOptions, result: Result<()>) -> Result<()> {
+    if result.is_err() {
+        *INIT_OPTIONS.lock().unwrap() = Some(options);
+    }
+    result
+}
+
+/// Removes the statics that were initialized by a previous attempt from the graph.
+fn remove_completed(adjacent: &mut Vec<(usize, HashSet<usize>)>) {
+    let completed = INIT_COMPLETED.lock().unwrap().iter().copied().collect::<HashSet<_>>();
+    adjacent.retain(|(i, _)| !completed.contains(i));
+    for (_, deps) in adjacent {
+        deps.retain(|i| !completed.contains(i));
+    }
+}
+
+/// Builds the dependency graph of all statics in `registry` (either [`INIT`] or
+/// [`THREAD_LOCAL_INIT`]) as a list of `(index, dependencies)` pairs, where indices refer to
+/// positions in `registry`.
+///
+/// Dependencies on unregistered symbols are handled according to `missing_deps`, and warnings are
+/// prefixed with `debug_prefix`. Statics initialized at compile time are never registered, and are
+/// always satisfied.
+#[cfg_attr(feature = "tracing", allow(unused_variables))]
+fn build_graph(
+    registry: &[Init],
+    missing_deps: MissingDepPolicy,
+    debug_prefix: &str,
+) -> std::result::Result<Vec<(usize, HashSet<usize>)>, InitError> {
+    let mut symbol_map: HashMap<SymbolKey, usize> = HashMap::with_capacity(registry.len());
+    for (i, init) in registry.iter().enumerate() {
+        if symbol_map.insert(init.symbol.key(), i).is_some() {
+            let key = init.symbol.key();
+            let count = registry.iter().filter(|init| init.symbol.key() == key).count();
+            return Err(InitError::Ambiguous {
+                symbol: init.symbol,
+                count,
+            });
+        }
+    }
+
+    let const_symbols = CONST_SYMBOLS.iter().map(|symbol| symbol.key()).collect::<HashSet<_>>();
+    let mut adjacent = Vec::with_capacity(registry.len());
+    for (i, init) in registry.iter().enumerate() {
+        let mut deps = HashSet::new();
+        for symbol in init.deps.symbols() {
+            match symbol_map.get(&symbol.key()) {
+                Some(&j) => {
+                    deps.insert(j);
+                }
+                None if const_symbols.contains(&symbol.key()) => {}
+                None => match missing_deps {
+                    MissingDepPolicy::Ignore => {}
+                    MissingDepPolicy::Warn => {
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!(
+                            ident = init.symbol.ident,
+                            module = init.symbol.module,
+                            dependency = symbol.ident,
+                            "dependency not registered with init_static",
+                        );
+                        #[cfg(not(feature = "tracing"))]
+                        eprintln!(
+                            "{debug_prefix} warning: {} depends on {symbol}, which is not registered",
+                            init.symbol
+                        );
+                    }
+                    MissingDepPolicy::Error => {
+                        return Err(InitError::MissingDependency {
+                            dependent: init.symbol,
+                            missing: symbol,
+                        });
+                    }
+                },
+            }
+        }
+        for symbol in init.weak_deps.iter().filter_map(|f| f()) {
+            if let Some(&j) = symbol_map.get(&symbol.key()) {
+                deps.insert(j);
+            }
+        }
+        if deps.contains(&i) {
+            return Err(InitError::SelfDependency { symbol: init.symbol });
+        }
+        adjacent.push((i, deps));
+    }
+    Ok(adjacent)
+}
+
+/// Restricts a freshly built graph to the statics matching `predicate` and their transitive
+/// dependencies.
+fn retain_selected(adjacent: &mut Vec<(usize, HashSet<usize>)>, predicate: impl Fn(&Symbol) -> bool) {
+    let mut selected = (0..INIT.len())
+        .filter(|&i| predicate(INIT[i].symbol))
+        .collect::<HashSet<_>>();
+    let mut stack = selected.iter().copied().collect::<Vec<_>>();
+    while let Some(i) = stack.pop() {
+        for &j in &adjacent[i].1 {
+            if selected.insert(j) {
+                stack.push(j);
+            }
+        }
+    }
+    adjacent.retain(|(i, _)| selected.contains(i));
+}
+
+/// Adds edges from every static to all statics of earlier stages, so that stages are initialized
+/// one after another.
+fn apply_stages(adjacent: &mut [(usize, HashSet<usize>)], stages: &[&str]) -> Result<()> {
+    let stage_of = |i: usize| {
+        let module = INIT[i].symbol.module;
+        stages
+            .iter()
+            .position(|prefix| {
+                module
+                    .strip_prefix(prefix)
+                    .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+            })
+            .unwrap_or(stages.len())
+    };
+    let stage_map = adjacent.iter().map(|(i, _)| stage_of(*i)).collect::<Vec<_>>();
+    for (i, deps) in adjacent.iter() {
+        if let Some(&j) = deps.iter().find(|&&j| stage_map[j] > stage_map[*i]) {
+            return Err(InitError::StageOrder {
+                symbol: INIT[*i].symbol,
+                dependency: INIT[j].symbol,
+            }
+            .into());
+        }
+    }
+    for (i, deps) in adjacent.iter_mut() {
+        deps.extend((0..stage_map.len()).filter(|&j| stage_map[j] < stage_map[*i]));
+    }
+    Ok(())
+}
+
+/// Restricts the graph to the statics listed in `order` and chains them, so that they are
+/// initialized one after another in that order.
+fn apply_order(adjacent: &mut Vec<(usize, HashSet<usize>)>, order: &[&'static Symbol]) -> Result<()> {
+    let mut position_map = HashMap::with_capacity(order.len());
+    for (position, symbol) in order.iter().enumerate() {
+        position_map.entry(symbol.key()).or_insert(position);
+    }
+    let position_of = |i: usize| position_map.get(&INIT[i].symbol.key()).copied();
+    adjacent.retain(|(i, _)| position_of(*i).is_some());
+    for (i, deps) in adjacent.iter() {
+        let position = position_of(*i);
+        if let Some(&j) = deps
+            .iter()
+            .find(|&&j| position_of(j).is_none_or(|p| Some(p) > position))
+        {
+            return Err(InitError::OrderViolation {
+                symbol: INIT[*i].symbol,
+                dependency: INIT[j].symbol,
+            }
+            .into());
+        }
+    }
+    adjacent.sort_by_key(|(i, _)| position_of(*i));
+    for k in 1..adjacent.len() {
+        let prev = adjacent[k - 1].0;
+        adjacent[k].1.insert(prev);
+    }
+    Ok(())
+}
+
+/// A dependency graph resolved with Kahn's algorithm.
+///
+/// Each static keeps a count of its unresolved dependencies, so that completing a static only
+/// touches its own dependents instead of the whole graph.
+struct Graph {
+    /// The dependencies of every static that has not been taken yet, used to report cycles.
+    deps: HashMap<usize, HashSet<usize>>,
+    /// The statics depending on each static.
+    dependents: HashMap<usize, Vec<usize>>,
+    /// The number of unresolved dependencies of every static that has not been taken yet.
+    in_degree: HashMap<usize, usize>,
+    /// The statics whose dependencies are all resolved, waiting to be taken.
+    ready: Vec<usize>,
+}
+
+impl Graph {
+    fn new(adjacent: Vec<(usize, HashSet<usize>)>) -> Self {
+        let mut dependents: HashMap<usize, Vec<usize>> = HashMap::with_capacity(adjacent.len());
+        let mut in_degree = HashMap::with_capacity(adjacent.len());
+        let mut ready = vec![];
+        for (i, deps) in &adjacent {
+            for &j in deps {
+                dependents.entry(j).or_default().push(*i);
+            }
+            in_degree.insert(*i, deps.len());
+            if deps.is_empty() {
+                ready.push(*i);
+            }
+        }
+        Self {
+            deps: adjacent.into_iter().collect(),
+            dependents,
+            in_degree,
+            ready,
+        }
+    }
+
+    /// Returns the number of statics that have not been taken yet.
+    fn len(&self) -> usize {
+        self.in_degree.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.in_degree.is_empty()
+    }
+
+    /// Removes and returns every static in the graph whose dependencies are all resolved.
+    ///
+    /// The layer is sorted in source order when `deterministic` is set, and in registry order
+    /// otherwise.
+    fn take_layer(&mut self, registry: &[Init], deterministic: bool) -> Vec<usize> {
+        let mut layer = std::mem::take(&mut self.ready);
+        for i in &layer {
+            self.in_degree.remove(i);
+            self.deps.remove(i);
+        }
+        if deterministic {
+            layer.sort_by_key(|&i| registry[i].symbol);
+        } else {
+            layer.sort_unstable();
+        }
+        layer
+    }
+
+    /// Returns the number of statics directly depending on `i`.
+    #[cfg(feature = "async")]
+    fn dependent_count(&self, i: usize) -> usize {
+        self.dependents.get(&i).map_or(0, Vec::len)
+    }
+
+    /// Marks a static as initialized, resolving it for its dependents.
+    fn complete(&mut self, i: usize) {
+        for &k in self.dependents.get(&i).into_iter().flatten() {
+            if let Some(n) = self.in_degree.get_mut(&k) {
+                *n -= 1;
+                if *n == 0 {
+                    self.ready.push(k);
+                }
+            }
+        }
+    }
+
+    /// Removes and returns every static that transitively depends on `i`.
+    fn remove_dependents(&mut self, i: usize) -> Vec<usize> {
+        let mut removed = vec![];
+        let mut stack = vec![i];
+        while let Some(j) = stack.pop() {
+            for &k in self.dependents.get(&j).into_iter().flatten() {
+                if self.in_degree.remove(&k).is_some() {
+                    self.deps.remove(&k);
+                    removed.push(k);
+                    stack.push(k);
+                }
+            }
+        }
+        removed
+    }
+
+    /// Extracts a dependency cycle from the statics that have not been taken yet, which must all
+    /// have unresolved dependencies.
+    fn circular_error(&self, registry: &[Init]) -> InitError {
+        let mut adjacent = self
+            .deps
+            .iter()
+            .map(|(&i, deps)| (i, deps.iter().copied().filter(|j| self.deps.contains_key(j)).collect()))
+            .collect::<Vec<_>>();
+        adjacent.sort_unstable_by_key(|(i, _)| *i);
+        circular_error(registry, &adjacent)
+    }
+}
+
+/// Extracts a dependency cycle from the remaining graph, where every node has at least one
+/// unresolved dependency.
+///
+/// The cycle is rotated to start at the symbol declared first in source order, so that the
+/// reported path is stable regardless of registration order.
+fn circular_error(registry: &[Init], adjacent: &[(usize, HashSet<usize>)]) -> InitError {
+    let graph = adjacent.iter().map(|(i, deps)| (*i, deps)).collect::<HashMap<_, _>>();
+    let mut visited = vec![];
+    // Follow the dependencies in source order, so that the reported cycle does not depend on the
+    // order in which the linker laid out the registry.
+    let mut i = adjacent
+        .iter()
+        .map(|(i, _)| *i)
+        .min_by_key(|&i| registry[i].symbol)
+        .unwrap();
+    while !visited.contains(&i) {
+        visited.push(i);
+        i = *graph[&i]
+            .iter()
+            .min_by_key(|&&j| registry[j].symbol)
+            .expect("remaining statics must have dependencies");
+    }
+    let start = visited.iter().position(|&j| j == i).unwrap();
+    let mut path = visited[start..].iter().map(|&j| registry[j].symbol).collect::<Vec<_>>();
+    let first = (0..path.len()).min_by_key(|&k| path[k]).unwrap();
+    path.rotate_left(first);
+    InitError::Circular { path }
+}
+
+/// Runs initialization for all statics declared with [`init_static!`].
+///
+/// This function iterates over all init functions registered via the macro and executes them once.
+/// Call this early in your program (e.g., at the beginning of `main()`) before accessing any
+/// [`struct@InitStatic`] values.
+///
+/// If initialization fails, it can be retried by calling this function again. Statics that were
+/// initialized successfully by a previous attempt are skipped.
+///
+/// Without the `async` feature, every static is synchronous and initialized on the calling thread
+/// as if by [`init_static_sync()`], so no futures dependencies are pulled in.
+///
+/// # Examples
+///
+/// ```
+/// use init_static::init_static;
+///
+/// init_static! {
+///     static VALUE: u32 = "42".parse()?;
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     init_static().await.unwrap();
+///     println!("{}", *VALUE);
+/// }
+/// ```
+pub async fn init_static() -> Result<()> {
+    init_static_filtered(|_| true).await
+}
+
+/// Runs initialization like [`init_static()`], making `context` available to the statics declared
+/// with `#[context(...)]`.
+///
+/// Such a static borrows the context through the binding named in the attribute, so that a shared
+/// handle (such as a configuration object) does not have to be stored in a global first. The
+/// context is dropped once initialization completes. A static whose context type does not match
+/// fails with [`InitError::MissingContext`].
+///
+/// # Example
+///
+/// ```standalone_crate
+/// use init_static::{init_static, init_static_with};
+///
+/// struct Config {
+///     url: String,
+/// }
+///
+/// init_static! {
+///     #[context(config: Config)]
+///     static URL: String = config.url.clone();
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let config = Config { url: "postgres://localhost".to_owned() };
+///     init_static_with(config).await.unwrap();
+///     assert_eq!(*URL, "postgres://localhost");
+/// }
+/// ```
+pub async fn init_static_with<C: Any + Send + Sync>(context: C) -> Result<()> {
+    *INIT_CONTEXT.lock().unwrap() = Some(Arc::new(context));
+    let result = init_static().await;
+    INIT_CONTEXT.lock().unwrap().take();
+    result
+}
+
+/// The context passed to [`init_static_with()`], while initialization is running.
+static INIT_CONTEXT: Mutex<Option<Arc<dyn Any + Send + Sync>>> = Mutex::new(None);
+
+/// Runs initialization like [`init_static()`], returning an [`InitReport`] that summarizes what ran.
+///
+/// This gives startup metrics in one place without setting up [`set_profile()`] or
+/// [`set_progress_hook()`].
+///
+/// # Example
+///
+/// ```standalone_crate
+/// use init_static::{init_static, init_static_report};
+///
+/// init_static! {
+///     static URL: String = "postgres://localhost".to_owned();
+///     static POOL: String = format!("pool({})", *URL);
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let report = init_static_report().await.unwrap();
+///     assert_eq!(report.total, 2);
+///     assert_eq!(report.order[0].ident, "URL");
+///     println!("initialized {} statics in {:?}", report.total, report.duration);
+/// }
+/// ```
+pub async fn init_static_report() -> Result<InitReport> {
+    let start = Instant::now();
+    let skip = INIT_COMPLETED.lock().unwrap().len();
+    init_static().await?;
+    let completed = INIT_COMPLETED.lock().unwrap()[skip..].to_vec();
+    let async_count = completed
+        .iter()
+        .filter(|&&i| matches!(INIT[i].init, InitFn::Async(_)))
+        .count();
+    Ok(InitReport {
+        total: completed.len(),
+        sync_count: completed.len() - async_count,
+        async_count,
+        order: completed.into_iter().map(|i| INIT[i].symbol).collect(),
+        duration: start.elapsed(),
+    })
+}
+
+/// Runs initialization like [`init_static()`], streaming its [`InitEvent`]s.
+///
+/// The returned future drives initialization, and the returned stream yields the events as
+/// statics start and finish, ending once the future completes. This suits async UI loops better
+/// than the synchronous callback of [`set_progress_hook()`], which still receives the events if
+/// set. Events are buffered, so a slow consumer never stalls initialization.
+///
+/// # Example
+///
+/// ```standalone_crate
+/// use futures_util::StreamExt;
+/// use init_static::{InitEvent, init_static, init_static_events};
+///
+/// init_static! {
+///     static URL: String = "postgres://localhost".to_owned();
+///     static POOL: String = format!("pool({})", *URL);
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let (mut events, init) = init_static_events();
+///     let handle = tokio::spawn(init);
+///     while let Some(event) = events.next().await {
+///         if let InitEvent::SyncEnd { symbol } = event {
+///             println!("initialized {}", symbol.ident);
+///         }
+///     }
+///     handle.await.unwrap().unwrap();
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub fn init_static_events() -> (
+    impl Stream<Item = InitEvent> + Send + Unpin,
+    impl Future<Output = Result<()>> + Send,
+) {
+    /// Ends the stream once initialization completes or is dropped.
+    struct Close(Arc<EventQueue>);
+
+    impl Drop for Close {
+        fn drop(&mut self) {
+            self.0.close();
+        }
+    }
+
+    let queue = Arc::new(EventQueue::default());
+    let close = Close(queue.clone());
+    let stream = futures_util::stream::poll_fn({
+        let queue = queue.clone();
+        move |cx| queue.poll_next(cx)
+    });
+    let init = async move {
+        let _close = close;
+        let mut options = take_options();
+        let progress_hook = options.progress_hook.clone();
+        options.progress_hook = Some(Arc::new({
+            let progress_hook = progress_hook.clone();
+            move |event| {
+                if let Some(hook) = &progress_hook {
+                    hook(event);
+                }
+                queue.push(event);
+            }
+        }));
+        let result = async {
+            let mut adjacent = build_graph(&INIT, options.missing_deps, options.debug_prefix)?;
+            remove_completed(&mut adjacent);
+            run(&options, adjacent).await
+        }
+        .await;
+        options.progress_hook = progress_hook;
+        restore_on_error(options, result)
+    };
+    (stream, init)
+}
+
+/// Runs initialization for the statics matching `predicate`, along with their transitive
+/// dependencies.
+///
+/// This behaves like [`init_static()`], except that statics outside the selection are left
+/// uninitialized, and accessing them later will panic as usual. It is useful for booting a single
+/// subsystem, e.g. for a migration tool that only needs the database statics.
+///
 /// # Example
 ///
 /// ```
-/// use init_static::init_static;
+/// use init_static::{InitStatic, init_static, init_static_filtered};
 ///
 /// init_static! {
-///     static VALUE: u32 = "42".parse()?;
+///     static URL: String = "postgres://localhost".to_owned();
+///     static POOL: String = format!("pool({})", *URL);
+///     static CACHE: String = "redis://localhost".to_owned();
 /// }
 ///
 /// #[tokio::main]
 /// async fn main() {
-///     init_static().await.unwrap();
-///     println!("{}", *VALUE);
+///     init_static_filtered(|symbol| symbol.ident == "POOL").await.unwrap();
+///     assert!(InitStatic::is_set(&URL));
+///     assert!(!InitStatic::is_set(&CACHE));
 /// }
 /// ```
-pub use init_static_macro::init_static;
-
-pub use crate::error::InitError;
-pub use crate::init_static::{InitStatic, Symbol};
-
-struct InitOptions {
-    debug: bool,
+pub async fn init_static_filtered(predicate: impl Fn(&Symbol) -> bool) -> Result<()> {
+    let options = take_options();
+    let result = async {
+        let mut adjacent = build_graph(&INIT, options.missing_deps, options.debug_prefix)?;
+        retain_selected(&mut adjacent, predicate);
+        remove_completed(&mut adjacent);
+        run(&options, adjacent).await
+    }
+    .await;
+    restore_on_error(options, result)
 }
 
-static INIT_OPTIONS: Mutex<Option<InitOptions>> = Mutex::new(Some(InitOptions { debug: false }));
-
-/// Enables or disables debug output during initialization.
+/// Runs initialization for all statics declared with [`init_static!`] in module-prefix stages.
 ///
-/// When debug mode is enabled, the initialization process prints messages
-/// to stderr indicating:
+/// Each static belongs to the first stage whose prefix matches its [`Symbol::module`] (either
+/// equal to the prefix or nested under it), or to an implicit final stage if none match. Statics
+/// are initialized in dependency order as usual, but no static of a later stage begins until every
+/// static of the earlier stages is done.
 ///
-/// - When each synchronous static is initialized
-/// - When each asynchronous static begins and completes initialization
+/// If a static depends on a static of a later stage, [`InitError::StageOrder`] is returned before
+/// any initializer runs.
 ///
-/// This is useful for diagnosing initialization order issues or performance
-/// problems during startup.
-pub fn set_debug(debug: bool) {
-    INIT_OPTIONS
-        .lock()
-        .unwrap()
-        .as_mut()
-        .expect("INIT_OPTIONS can only be modified before `init_static` is called.")
-        .debug = debug;
-}
-
-/// Returns whether [`init_static()`] has already been called.
+/// # Example
 ///
-/// This function checks if the initialization process has been executed. It returns `true` if
-/// [`init_static()`] has been called (regardless of whether it succeeded or failed), and `false`
-/// otherwise.
-pub fn is_initialized() -> bool {
-    INIT_OPTIONS.lock().unwrap().is_none()
+/// ```no_run
+/// # async fn example() -> init_static::Result<()> {
+/// init_static::init_static_stages(&["myapp::config", "myapp::db"]).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn init_static_stages(stages: &[&str]) -> Result<()> {
+    let options = take_options();
+    let result = async {
+        let mut adjacent = build_graph(&INIT, options.missing_deps, options.debug_prefix)?;
+        apply_stages(&mut adjacent, stages)?;
+        remove_completed(&mut adjacent);
+        run(&options, adjacent).await
+    }
+    .await;
+    restore_on_error(options, result)
 }
 
-/// Runs initialization for all statics declared with [`init_static!`].
+/// Runs initialization for the statics listed in `order`, strictly one after another in that
+/// order, regardless of the order [`init_static()`] would compute.
 ///
-/// This function iterates over all init functions registered via the macro and executes them once.
-/// Call this early in your program (e.g., at the beginning of `main()`) before accessing any
-/// [`struct@InitStatic`] values.
+/// This is a debugging tool for reproducing order-sensitive issues deterministically. Every
+/// dependency of a listed static must be listed before it (or already be initialized), otherwise
+/// [`InitError::OrderViolation`] is returned before any initializer runs. Statics that are not
+/// listed are left uninitialized, and symbols that are not registered are ignored.
 ///
-/// # Examples
+/// # Example
 ///
 /// ```
-/// use init_static::init_static;
+/// use init_static::{InitStatic, init_static, init_static_ordered};
 ///
 /// init_static! {
-///     static VALUE: u32 = "42".parse()?;
+///     static URL: String = "postgres://localhost".to_owned();
+///     static CACHE: String = "redis://localhost".to_owned();
+///     static POOL: String = format!("pool({})", *URL);
 /// }
 ///
 /// #[tokio::main]
 /// async fn main() {
-///     init_static().await.unwrap();
-///     println!("{}", *VALUE);
+///     let symbols = [&CACHE, &URL, &POOL].map(InitStatic::symbol);
+///     init_static_ordered(&symbols).await.unwrap();
+///     assert_eq!(*POOL, "pool(postgres://localhost)");
 /// }
 /// ```
-pub async fn init_static() -> Result<()> {
-    let options = INIT_OPTIONS
-        .lock()
-        .unwrap()
-        .take()
-        .expect("`init_static` can only be called once.");
+pub async fn init_static_ordered(order: &[&'static Symbol]) -> Result<()> {
+    let options = take_options();
+    let result = async {
+        let mut adjacent = build_graph(&INIT, options.missing_deps, options.debug_prefix)?;
+        remove_completed(&mut adjacent);
+        apply_order(&mut adjacent, order)?;
+        run(&options, adjacent).await
+    }
+    .await;
+    restore_on_error(options, result)
+}
 
-    let mut symbol_map: HashMap<&'static Symbol, usize> = HashMap::new();
-    for (i, init) in INIT.iter().enumerate() {
-        if symbol_map.insert(init.symbol, i).is_some() {
-            return Err(InitError::Ambiguous { symbol: init.symbol }.into());
+/// Drives the given graph to completion, enforcing the total timeout if one is set.
+#[cfg(feature = "async")]
+async fn run(options: &InitOptions, adjacent: Vec<(usize, HashSet<usize>)>) -> Result<()> {
+    let Some(total_timeout) = options.total_timeout else {
+        return drive(options, adjacent).await;
+    };
+    let start = Instant::now();
+    match select(pin!(drive(options, adjacent)), Delay::new(total_timeout)).await {
+        Either::Left((output, _)) => output,
+        Either::Right(_) => Err(InitError::TotalTimeout {
+            elapsed: start.elapsed(),
         }
+        .into()),
     }
+}
 
-    let mut adjacent = INIT
-        .iter()
-        .enumerate()
-        .map(|(i, init)| {
-            let deps = (init.deps)()
-                .into_iter()
-                .filter_map(|symbol| Some(*symbol_map.get(symbol?)?))
-                .collect::<HashSet<_>>();
-            (i, deps)
-        })
-        .collect::<Vec<_>>();
+/// Drives the given graph to completion with the synchronous driver, as async statics cannot be
+/// declared without the `async` feature.
+#[cfg(not(feature = "async"))]
+async fn run(options: &InitOptions, adjacent: Vec<(usize, HashSet<usize>)>) -> Result<()> {
+    drive_sync(options, adjacent)
+}
+
+#[cfg(feature = "async")]
+async fn drive(options: &InitOptions, adjacent: Vec<(usize, HashSet<usize>)>) -> Result<()> {
+    let mut graph = Graph::new(adjacent);
+    let mut failures = options.collect_errors.then(Failures::default);
+
+    INIT_TIMINGS.lock().unwrap().clear();
+    options.warn_unmanaged(&graph);
+    options.note_async_without_deps(&graph);
+    options.emit(InitEvent::Started { total: graph.len() });
 
+    let run_async = |i: usize| options.run_async(i);
+    #[cfg(feature = "tracing")]
+    let run_async = |i: usize| {
+        let symbol = INIT[i].symbol;
+        let span = tracing::debug_span!(
+            "init_static",
+            ident = symbol.ident,
+            module = symbol.module,
+            file = symbol.file,
+            line = symbol.line,
+        );
+        tracing::Instrument::instrument(run_async(i), span)
+    };
+
+    // In deterministic mode, async statics are awaited one at a time in the order they are queued.
+    let max_concurrency = if options.deterministic {
+        Some(1)
+    } else {
+        options.max_concurrency
+    };
+    let mut queue = VecDeque::with_capacity(graph.len());
     let mut join_set = FuturesUnordered::new();
-    while !adjacent.is_empty() || !join_set.is_empty() {
-        let layer = adjacent
-            .extract_if(.., |(_, deps)| deps.is_empty())
-            .map(|(i, _)| i)
-            .collect::<HashSet<_>>();
+    while !graph.is_empty() || !queue.is_empty() || !join_set.is_empty() {
+        let layer = graph.take_layer(&INIT, options.deterministic);
         let mut has_sync = false;
         for i in layer {
             match &INIT[i].init {
                 InitFn::Sync(f) => {
                     has_sync = true;
-                    if options.debug {
-                        eprintln!("init_static: sync {}", INIT[i].symbol);
-                    }
-                    f().with_context(|| format!("failed to initialize {}", INIT[i].symbol))?;
-                    for (_, deps) in &mut adjacent {
-                        deps.remove(&i);
-                    }
+                    let output = options.run_sync(i, *f);
+                    settle(&mut graph, &mut failures, i, output)?;
                 }
-                InitFn::Async(f) => join_set.push(async move {
-                    if options.debug {
-                        eprintln!("init_static: async begin {}", INIT[i].symbol);
-                    }
-                    let output = f()
-                        .await
-                        .map(|_| i)
-                        .with_context(|| format!("failed to initialize {}", INIT[i].symbol));
-                    if options.debug {
-                        eprintln!("init_static: async end {}", INIT[i].symbol);
-                    }
-                    output
-                }),
+                InitFn::Async(_) => queue.push_back(i),
             }
         }
         if has_sync {
             continue;
         }
+        if options.prioritize_dependents {
+            queue
+                .make_contiguous()
+                .sort_by_key(|&i| std::cmp::Reverse(graph.dependent_count(i)));
+        }
+        while max_concurrency.is_none_or(|n| join_set.len() < n)
+            && let Some(i) = queue.pop_front()
+        {
+            join_set.push(run_async(i));
+        }
         if join_set.is_empty() {
-            return Err(InitError::Circular {
-                symbols: adjacent.iter().map(|(i, _)| INIT[*i].symbol).collect(),
+            return Err(graph.circular_error(&INIT).into());
+        }
+        let (i, output) = join_set.next().await.unwrap();
+        settle(&mut graph, &mut failures, i, output)?;
+    }
+
+    options.emit(InitEvent::Finished);
+    Failures::into_result(failures)
+}
+
+#[derive(Default)]
+struct Failures {
+    errors: Vec<(&'static Symbol, Error)>,
+    skipped: Vec<(&'static Symbol, &'static Symbol)>,
+}
+
+impl Failures {
+    /// Reports the collected failures, if any, as [`InitError::Multiple`].
+    fn into_result(this: Option<Self>) -> Result<()> {
+        match this {
+            Some(failures) if !failures.errors.is_empty() => Err(InitError::Multiple {
+                errors: failures.errors,
+                skipped: failures.skipped,
             }
-            .into());
+            .into()),
+            _ => Ok(()),
         }
-        let i = join_set.next().await.unwrap()?;
-        for (_, deps) in &mut adjacent {
-            deps.remove(&i);
+    }
+}
+
+/// Records the outcome of initializing `INIT[i]` and updates the dependency graph accordingly.
+///
+/// On failure, the error is returned immediately unless `failures` is present, in which case it is
+/// recorded and every static that transitively depends on `INIT[i]` is removed from the graph and
+/// marked as skipped because of it.
+fn settle(graph: &mut Graph, failures: &mut Option<Failures>, i: usize, output: Result<()>) -> Result<()> {
+    let symbol = INIT[i].symbol;
+    let mut outcomes = INIT_OUTCOMES.lock().unwrap();
+    match (output, failures) {
+        (Ok(()), _) => {
+            outcomes.insert(symbol.key(), InitOutcome::Ok);
+            INIT_COMPLETED.lock().unwrap().push(i);
+            graph.complete(i);
+        }
+        (Err(e), Some(failures)) => {
+            outcomes.insert(symbol.key(), InitOutcome::Failed(format!("{e:#}")));
+            let skipped = graph.remove_dependents(i);
+            for &k in &skipped {
+                outcomes.insert(INIT[k].symbol.key(), InitOutcome::Skipped(symbol));
+            }
+            failures.errors.push((symbol, e));
+            failures
+                .skipped
+                .extend(skipped.into_iter().map(|k| (INIT[k].symbol, symbol)));
+        }
+        (Err(e), None) => {
+            outcomes.insert(symbol.key(), InitOutcome::Failed(format!("{e:#}")));
+            return Err(execution_error(symbol, e));
+        }
+    }
+    Ok(())
+}
+
+/// Runs initialization for all statics declared with [`init_static!`] without any async machinery.
+///
+/// This is intended for programs where every static is synchronous. If any async static is
+/// registered, [`InitError::AsyncInNonAsyncContext`] is returned before any initializer runs.
+///
+/// # Examples
+///
+/// ```standalone_crate
+/// use init_static::{init_static, init_static_sync};
+///
+/// init_static! {
+///     static VALUE: u32 = "42".parse()?;
+/// }
+///
+/// fn main() {
+///     init_static_sync().unwrap();
+///     println!("{}", *VALUE);
+/// }
+/// ```
+pub fn init_static_sync() -> Result<()> {
+    let options = take_options();
+    let result = (|| {
+        let mut adjacent = build_graph(&INIT, options.missing_deps, options.debug_prefix)?;
+        if let Some(init) = INIT.iter().find(|init| matches!(init.init, InitFn::Async(_))) {
+            return Err(InitError::AsyncInNonAsyncContext { symbol: init.symbol }.into());
+        }
+        remove_completed(&mut adjacent);
+        drive_sync(&options, adjacent)
+    })();
+    restore_on_error(options, result)
+}
+
+fn drive_sync(options: &InitOptions, adjacent: Vec<(usize, HashSet<usize>)>) -> Result<()> {
+    let mut graph = Graph::new(adjacent);
+    let mut failures = options.collect_errors.then(Failures::default);
+
+    INIT_TIMINGS.lock().unwrap().clear();
+    options.warn_unmanaged(&graph);
+    options.emit(InitEvent::Started { total: graph.len() });
+    while !graph.is_empty() {
+        let layer = graph.take_layer(&INIT, options.deterministic);
+        if layer.is_empty() {
+            return Err(graph.circular_error(&INIT).into());
+        }
+        for i in layer {
+            let InitFn::Sync(f) = &INIT[i].init else {
+                unreachable!();
+            };
+            let output = options.run_sync(i, *f);
+            settle(&mut graph, &mut failures, i, output)?;
         }
     }
 
+    options.emit(InitEvent::Finished);
+    Failures::into_result(failures)
+}
+
+thread_local! {
+    /// Indices into [`THREAD_LOCAL_INIT`] of the thread-locals that have been initialized on the
+    /// current thread.
+    static THREAD_LOCAL_COMPLETED: RefCell<HashSet<usize>> = RefCell::new(HashSet::new());
+}
+
+/// Runs the initializers of all thread-locals declared with [`init_thread_local!`] on the current
+/// thread.
+///
+/// Thread-locals are initialized in dependency order, as with [`init_static_sync()`], but only for
+/// the calling thread, so this must be called on every thread that accesses them. Values already
+/// initialized on the current thread are skipped, which makes calling this again harmless and
+/// allows retrying after a failure. The options set with functions such as [`set_debug()`] do not
+/// apply to thread-locals.
+pub fn init_thread_local() -> Result<()> {
+    let mut adjacent = build_graph(&THREAD_LOCAL_INIT, MissingDepPolicy::Ignore, DEFAULT_DEBUG_PREFIX)?;
+    THREAD_LOCAL_COMPLETED.with_borrow(|completed| {
+        adjacent.retain(|(i, _)| !completed.contains(i));
+        for (_, deps) in &mut adjacent {
+            deps.retain(|i| !completed.contains(i));
+        }
+    });
+    let mut graph = Graph::new(adjacent);
+    while !graph.is_empty() {
+        let layer = graph.take_layer(&THREAD_LOCAL_INIT, false);
+        if layer.is_empty() {
+            return Err(graph.circular_error(&THREAD_LOCAL_INIT).into());
+        }
+        for i in layer {
+            let InitFn::Sync(f) = &THREAD_LOCAL_INIT[i].init else {
+                unreachable!();
+            };
+            f().map_err(|e| execution_error(THREAD_LOCAL_INIT[i].symbol, e))?;
+            THREAD_LOCAL_COMPLETED.with_borrow_mut(|completed| completed.insert(i));
+            graph.complete(i);
+        }
+    }
     Ok(())
 }
 
+/// Checks that every [`struct@InitStatic`] value has been initialized.
+///
+/// This covers every value created with the [`InitStatic!`] macro, including those declared
+/// manually rather than through [`init_static!`], which are not part of the registry and would
+/// otherwise only be discovered when accessed. Call it after [`init_static()`] (and any manual
+/// [`InitStatic::init`] calls) to catch such misuse early.
+///
+/// Returns [`InitError::Uninitialized`] listing every value that is still unset.
+///
+/// # Example
+///
+/// ```standalone_crate
+/// use init_static::{InitStatic, init_static, verify_all_initialized};
+///
+/// static MANUAL: InitStatic<u32> = InitStatic!(MANUAL);
+///
+/// #[tokio::main]
+/// async fn main() {
+///     init_static().await.unwrap();
+///     assert!(verify_all_initialized().is_err());
+///     InitStatic::init(&MANUAL, 42);
+///     verify_all_initialized().unwrap();
+/// }
+/// ```
+pub fn verify_all_initialized() -> Result<()> {
+    let initialized = crate::init_static::INITIALIZED_SYMBOLS.lock().unwrap();
+    let mut symbols = SYMBOLS
+        .iter()
+        .copied()
+        .filter(|symbol| !initialized.contains(&symbol.key()))
+        .collect::<Vec<_>>();
+    if symbols.is_empty() {
+        return Ok(());
+    }
+    symbols.sort();
+    Err(InitError::Uninitialized { symbols }.into())
+}
+
+/// Runs the teardown functions registered with `#[deinit(...)]` for every initialized static.
+///
+/// Teardown functions run one at a time in the reverse order in which the statics finished
+/// initializing, so a static is always torn down before any of its dependencies. Statics that were
+/// never initialized are skipped, and each teardown function runs at most once: subsequent calls
+/// only tear down statics initialized since the previous call.
+///
+/// The values themselves remain accessible after teardown, so any state invalidated by a teardown
+/// function (e.g. a closed connection pool) must be handled by the caller.
+///
+/// Since statics are never dropped, values that must run their [`Drop`] implementation at exit
+/// (e.g. a logger that flushes) can opt in with `#[drop_on_deinit]`. The value is then taken out
+/// with [`InitStatic::take`] and dropped during teardown, after its `#[deinit(...)]` function if
+/// any, so it must not be accessed afterwards. Taking the value requires exclusive access, so the
+/// static must be declared as `static mut`:
+///
+/// ```
+/// use init_static::{deinit_static, init_static};
+///
+/// struct Logger;
+///
+/// impl Drop for Logger {
+///     fn drop(&mut self) {
+///         println!("flushing logs");
+///     }
+/// }
+///
+/// init_static! {
+///     #[drop_on_deinit]
+///     static mut LOGGER: Logger = Logger;
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     init_static().await.unwrap();
+///     deinit_static().await;
+/// }
+/// ```
+///
+/// # Example
+///
+/// ```
+/// use init_static::{deinit_static, init_static};
+///
+/// fn close(name: &String) {
+///     println!("closing {name}");
+/// }
+///
+/// init_static! {
+///     #[deinit(close)]
+///     static POOL: String = "pool".to_owned();
+/// }
+///
+/// #[tokio::main]
+/// async fn main() {
+///     init_static().await.unwrap();
+///     deinit_static().await;
+/// }
+/// ```
+pub async fn deinit_static() {
+    let deinit_map = DEINIT
+        .iter()
+        .map(|deinit| (deinit.symbol, &deinit.deinit))
+        .collect::<HashMap<_, _>>();
+    let completed = std::mem::take(&mut *INIT_COMPLETED.lock().unwrap());
+    for i in completed.into_iter().rev() {
+        match deinit_map.get(INIT[i].symbol) {
+            Some(DeinitFn::Sync(f)) => f(),
+            Some(DeinitFn::Async(f)) => f().await,
+            None => {}
+        }
+    }
+}
+
+/// Runs initialization for all statics declared with [`init_static!`], blocking the current thread
+/// until it completes.
+///
+/// This is the synchronous counterpart of [`init_static()`] for programs that have no async runtime.
+/// Async statics are driven by a minimal executor on the calling thread, so they will block it and
+/// must not rely on a specific runtime (such as tokio's reactor or timers). When every static is
+/// synchronous, no async machinery is involved beyond polling a future that is immediately ready.
+///
+/// Like [`init_static()`], this returns the crate's [`Result`] rather than `Result<(), InitError>`,
+/// because the error may come from an initializer itself (e.g. a parse error returned with `?`).
+/// Errors raised by `init_static` can still be inspected by downcasting to [`InitError`].
+///
+/// # Examples
+///
+/// ```
+/// use init_static::{init_static, init_static_blocking};
+///
+/// init_static! {
+///     static VALUE: u32 = "42".parse()?;
+/// }
+///
+/// fn main() {
+///     init_static_blocking().unwrap();
+///     println!("{}", *VALUE);
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub fn init_static_blocking() -> Result<()> {
+    futures_executor::block_on(init_static())
+}
+
+/// Runs initialization for all statics declared with [`init_static!`] on an existing tokio runtime,
+/// blocking the current thread until it completes.
+///
+/// Unlike [`init_static_blocking()`], async statics run within the context of the given runtime,
+/// so they may use its reactor and timers. This is meant for a synchronous `main` that creates
+/// its runtime itself, instead of using `#[tokio::main]`. Like
+/// [`Handle::block_on`](tokio::runtime::Handle::block_on), this panics if called from within an
+/// async context.
+///
+/// # Examples
+///
+/// ```standalone_crate
+/// use init_static::{init_static, init_static_on};
+///
+/// init_static! {
+///     static VALUE: u32 = async {
+///         tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+///         42
+///     }.await;
+/// }
+///
+/// fn main() {
+///     let runtime = tokio::runtime::Runtime::new().unwrap();
+///     init_static_on(runtime.handle()).unwrap();
+///     println!("{}", *VALUE);
+/// }
+/// ```
+#[cfg(feature = "tokio")]
+pub fn init_static_on(handle: &tokio::runtime::Handle) -> Result<()> {
+    handle.block_on(init_static())
+}
+
 #[doc(hidden)]
 pub mod __private {
+    use std::any::Any;
     use std::pin::Pin;
+    use std::sync::Arc;
 
-    pub use {anyhow, linkme};
+    #[cfg(feature = "anyhow")]
+    pub use anyhow;
+    pub use linkme;
+
+    pub use crate::Result;
 
-    use crate::Symbol;
     pub use crate::init_static::MaybeInitStatic;
+    use crate::{InitError, Symbol};
+
+    /// Emits a compile error for async statics when the `async` feature is disabled.
+    #[cfg(feature = "async")]
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! __require_async {
+        ($name:ident) => {};
+    }
+
+    /// Emits a compile error for async statics when the `async` feature is disabled, pointing at
+    /// the name of the static.
+    #[cfg(not(feature = "async"))]
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! __require_async {
+        ($name:ident) => {
+            compile_error!(concat!(
+                "async static `",
+                stringify!($name),
+                "` requires the `async` feature of `init_static`",
+            ));
+        };
+    }
+
+    pub use __require_async as require_async;
+
+    /// Places a static in one of the registries below. The macros always emit this attribute, and
+    /// the backend is selected here, so that the `cfg` is only evaluated in this crate.
+    #[cfg(not(any(init_static_ctor, target_family = "wasm")))]
+    pub use linkme::distributed_slice;
+
+    #[cfg(any(init_static_ctor, target_family = "wasm"))]
+    pub use init_static_macro::__distributed_slice as distributed_slice;
+
+    /// Adds a static to one of the registries below, by pushing it from a constructor that runs
+    /// before `main`. This is emitted by the constructor-based `distributed_slice` above.
+    #[cfg(any(init_static_ctor, target_family = "wasm"))]
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! __register {
+        ($registry:path, $name:ident) => {
+            $crate::__private::used! {
+                #[cfg_attr(
+                    any(target_os = "macos", target_os = "ios"),
+                    unsafe(link_section = "__DATA,__mod_init_func")
+                )]
+                #[cfg_attr(windows, unsafe(link_section = ".CRT$XCU"))]
+                #[cfg_attr(
+                    not(any(target_os = "macos", target_os = "ios", windows)),
+                    unsafe(link_section = ".init_array")
+                )]
+                static CTOR: extern "C" fn() = {
+                    extern "C" fn ctor() {
+                        $registry.push(&$name);
+                    }
+                    ctor
+                };
+            }
+        };
+    }
+
+    #[cfg(any(init_static_ctor, target_family = "wasm"))]
+    pub use __register as register;
+
+    /// Marks a constructor static as used, in an anonymous `const` block. With the `used_linker`
+    /// feature, it is kept by the linker as well, like the entries of the distributed slices.
+    #[cfg(all(any(init_static_ctor, target_family = "wasm"), not(feature = "used_linker")))]
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! __used {
+        ($($item:tt)*) => {
+            const _: () = {
+                #[used]
+                $($item)*
+            };
+        };
+    }
+
+    #[cfg(all(any(init_static_ctor, target_family = "wasm"), feature = "used_linker"))]
+    #[doc(hidden)]
+    #[macro_export]
+    macro_rules! __used {
+        ($($item:tt)*) => {
+            const _: () = {
+                #[used(linker)]
+                $($item)*
+            };
+        };
+    }
+
+    #[cfg(any(init_static_ctor, target_family = "wasm"))]
+    pub use __used as used;
+
+    /// A registry filled by constructors before `main`, used in place of `linkme` distributed
+    /// slices on targets where they are unavailable.
+    ///
+    /// The entries are frozen into a slice when the registry is first dereferenced.
+    #[cfg(any(init_static_ctor, target_family = "wasm"))]
+    pub struct Registry<T: 'static> {
+        pending: std::sync::Mutex<Vec<&'static T>>,
+        entries: std::sync::OnceLock<Vec<T>>,
+    }
+
+    #[cfg(any(init_static_ctor, target_family = "wasm"))]
+    impl<T: Clone> Registry<T> {
+        #[allow(clippy::new_without_default)]
+        pub const fn new() -> Self {
+            Self {
+                pending: std::sync::Mutex::new(Vec::new()),
+                entries: std::sync::OnceLock::new(),
+            }
+        }
+
+        pub fn push(&self, entry: &'static T) {
+            assert!(self.entries.get().is_none(), "registry is already frozen");
+            self.pending.lock().unwrap().push(entry);
+        }
+    }
+
+    #[cfg(any(init_static_ctor, target_family = "wasm"))]
+    impl<T: Clone> std::ops::Deref for Registry<T> {
+        type Target = [T];
+
+        fn deref(&self) -> &[T] {
+            self.entries.get_or_init(|| {
+                self.pending
+                    .lock()
+                    .unwrap()
+                    .iter()
+                    .map(|&entry| entry.clone())
+                    .collect()
+            })
+        }
+    }
+
+    pub type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+    /// Returns the context passed to `init_static_with()` for a static declared with
+    /// `#[context(...)]`.
+    pub fn context<C: Any + Send + Sync>(symbol: &'static Symbol) -> std::result::Result<Arc<C>, InitError> {
+        crate::INIT_CONTEXT
+            .lock()
+            .unwrap()
+            .clone()
+            .and_then(|context| context.downcast().ok())
+            .ok_or(InitError::MissingContext {
+                symbol,
+                type_name: std::any::type_name::<C>(),
+            })
+    }
 
-    pub type BoxFuture<T> = Pin<Box<dyn Future<Output = T>>>;
+    pub type SyncInitFn = dyn Fn() -> Result<()> + Send + Sync;
+    pub type AsyncInitFn = dyn Fn() -> BoxFuture<Result<()>> + Send + Sync;
+    pub type DepFn = fn() -> Option<&'static Symbol>;
+    pub type DepsFn = dyn Fn() -> Vec<Option<&'static Symbol>> + Send + Sync;
+    pub type UnmanagedFn = dyn Fn() -> Vec<&'static str> + Send + Sync;
 
+    #[derive(Clone, Copy)]
     pub enum InitFn {
-        Sync(fn() -> anyhow::Result<()>),
-        Async(fn() -> BoxFuture<anyhow::Result<()>>),
+        Sync(&'static SyncInitFn),
+        Async(&'static AsyncInitFn),
+    }
+
+    /// The dependencies of a static.
+    #[derive(Clone, Copy)]
+    pub enum Deps {
+        /// Dependencies detected by the macro, each resolved to a symbol (or `None` if it is not
+        /// a static) by a function.
+        Static(&'static [DepFn]),
+        /// Dependencies registered with `StaticBuilder`.
+        Symbols(&'static [&'static Symbol]),
+        /// Dependencies computed by the function given via `#[deps_fn(...)]`.
+        Dynamic(&'static DepsFn),
+    }
+
+    impl Deps {
+        /// Returns the symbols of the dependencies, skipping those that are not statics.
+        pub(crate) fn symbols(self) -> impl Iterator<Item = &'static Symbol> {
+            let (fns, symbols, dynamic) = match self {
+                Self::Static(fns) => (fns, &[][..], Vec::new()),
+                Self::Symbols(symbols) => (&[][..], symbols, Vec::new()),
+                Self::Dynamic(f) => (&[][..], &[][..], f()),
+            };
+            let symbols = symbols.iter().copied().map(Some);
+            fns.iter().map(|f| f()).chain(symbols).chain(dynamic).flatten()
+        }
     }
 
+    #[derive(Clone, Copy)]
     pub struct Init {
         pub symbol: &'static Symbol,
         pub init: InitFn,
-        pub deps: fn() -> Vec<Option<&'static Symbol>>,
+        pub deps: Deps,
+        pub weak_deps: &'static [DepFn],
+        pub unmanaged: &'static UnmanagedFn,
     }
 
+    #[cfg(not(any(init_static_ctor, target_family = "wasm")))]
     #[linkme::distributed_slice]
     pub static INIT: [Init];
+
+    #[cfg(any(init_static_ctor, target_family = "wasm"))]
+    pub static INIT: Registry<Init> = Registry::new();
+
+    #[cfg(not(any(init_static_ctor, target_family = "wasm")))]
+    #[linkme::distributed_slice]
+    pub static THREAD_LOCAL_INIT: [Init];
+
+    #[cfg(any(init_static_ctor, target_family = "wasm"))]
+    pub static THREAD_LOCAL_INIT: Registry<Init> = Registry::new();
+
+    #[derive(Clone, Copy)]
+    pub enum DeinitFn {
+        Sync(fn()),
+        Async(fn() -> BoxFuture<()>),
+    }
+
+    #[derive(Clone, Copy)]
+    pub struct Deinit {
+        pub symbol: &'static Symbol,
+        pub deinit: DeinitFn,
+    }
+
+    #[cfg(not(any(init_static_ctor, target_family = "wasm")))]
+    #[linkme::distributed_slice]
+    pub static DEINIT: [Deinit];
+
+    #[cfg(any(init_static_ctor, target_family = "wasm"))]
+    pub static DEINIT: Registry<Deinit> = Registry::new();
+
+    #[cfg(not(any(init_static_ctor, target_family = "wasm")))]
+    #[linkme::distributed_slice]
+    pub static SYMBOLS: [&'static Symbol];
+
+    #[cfg(any(init_static_ctor, target_family = "wasm"))]
+    pub static SYMBOLS: Registry<&'static Symbol> = Registry::new();
+
+    /// The symbol of a static created with `Symbol!(MY_VALUE: Type)`, paired with the name of its
+    /// type.
+    pub type TypeName = (&'static Symbol, TypeNameFn);
+    pub type TypeNameFn = fn() -> &'static str;
+
+    #[cfg(not(any(init_static_ctor, target_family = "wasm")))]
+    #[linkme::distributed_slice]
+    pub static TYPE_NAMES: [TypeName];
+
+    #[cfg(any(init_static_ctor, target_family = "wasm"))]
+    pub static TYPE_NAMES: Registry<TypeName> = Registry::new();
+
+    /// The symbols of statics that `init_static!` initializes at compile time, which satisfy any
+    /// dependency on them without an init function.
+    #[cfg(not(any(init_static_ctor, target_family = "wasm")))]
+    #[linkme::distributed_slice]
+    pub static CONST_SYMBOLS: [&'static Symbol];
+
+    #[cfg(any(init_static_ctor, target_family = "wasm"))]
+    pub static CONST_SYMBOLS: Registry<&'static Symbol> = Registry::new();
 }
@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+use crate::Symbol;
+
+/// The outcome of initializing a static with [`init_static()`](crate::init_static()), returned by
+/// [`init_outcome()`](crate::init_outcome()).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InitOutcome {
+    /// The static was initialized successfully.
+    Ok,
+
+    /// The initializer of the static failed. The string is the error, formatted with `{:#}` so
+    /// that its whole chain is included.
+    Failed(String),
+
+    /// The static was not initialized because the given dependency failed, while
+    /// [`set_collect_errors()`](crate::set_collect_errors()) was enabled.
+    Skipped(&'static Symbol),
+}
+
+/// A summary of an initialization run, returned by
+/// [`init_static_report()`](crate::init_static_report()).
+///
+/// Statics that were already initialized by an earlier, failed run are not included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InitReport {
+    /// The number of statics initialized.
+    pub total: usize,
+
+    /// The number of synchronous statics initialized.
+    pub sync_count: usize,
+
+    /// The number of asynchronous statics initialized.
+    pub async_count: usize,
+
+    /// The statics initialized, in completion order.
+    pub order: Vec<&'static Symbol>,
+
+    /// The wall-clock duration of the run.
+    pub duration: Duration,
+}
@@ -0,0 +1,18 @@
+use init_static::{InitError, InitStatic, init_static, register_init};
+
+init_static! {
+    static VALUE: u32 = "1".parse()?;
+}
+
+#[tokio::test]
+async fn main() {
+    register_init(InitStatic::symbol(&VALUE), &[], || Ok(()));
+    register_init(InitStatic::symbol(&VALUE), &[], || Ok(()));
+    let e = init_static().await.unwrap_err();
+    let Some(InitError::Ambiguous { symbol, count }) = e.downcast_ref::<InitError>() else {
+        panic!("expected InitError::Ambiguous, got {e:?}");
+    };
+    assert_eq!(symbol.ident, "VALUE");
+    assert_eq!(*count, 3);
+    assert!(e.to_string().contains("is defined 3 times"));
+}
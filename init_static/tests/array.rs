@@ -0,0 +1,32 @@
+use init_static::{dependency_graph, init_static};
+
+struct Worker {
+    id: usize,
+    pool: &'static str,
+}
+
+init_static! {
+    static POOL: String = "pool".parse()?;
+    static WORKERS: [Worker; 4] = std::array::from_fn(|id| Worker { id, pool: &POOL });
+    static LAST: usize = WORKERS[WORKERS.len() - 1].id;
+}
+
+#[tokio::test]
+async fn main() {
+    let graph = dependency_graph();
+    let deps_of = |ident| {
+        let (_, deps) = graph.iter().find(|(symbol, _)| symbol.ident == ident).unwrap();
+        deps.iter().map(|symbol| symbol.ident).collect::<Vec<_>>()
+    };
+    assert_eq!(deps_of("WORKERS"), ["POOL"]);
+    assert_eq!(deps_of("LAST"), ["WORKERS"]);
+
+    init_static().await.unwrap();
+    assert!(
+        WORKERS
+            .iter()
+            .enumerate()
+            .all(|(i, worker)| worker.id == i && worker.pool == "pool")
+    );
+    assert_eq!(*LAST, 3);
+}
@@ -0,0 +1,12 @@
+use init_static::{init_static, init_static_blocking};
+
+init_static! {
+    static FOO: u32 = async { *BAR + 1 }.await;
+    static BAR: u32 = "42".parse()?;
+}
+
+#[test]
+fn main() {
+    init_static_blocking().unwrap();
+    assert_eq!(*FOO, 43);
+}
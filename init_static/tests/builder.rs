@@ -0,0 +1,31 @@
+use init_static::{InitStatic, MissingDepPolicy, StaticBuilder, init_static, register_init, set_missing_dep_policy};
+
+init_static! {
+    static BASE: u32 = 1;
+    static DOUBLED: u32 = *PLUGIN * 2;
+}
+
+static PLUGIN: InitStatic<u32> = InitStatic!(PLUGIN);
+static EXTRA: InitStatic<u32> = InitStatic!(EXTRA);
+
+#[tokio::test]
+async fn main() {
+    // `BASE` is initialized at compile time, and still satisfies the dependency of `PLUGIN`.
+    set_missing_dep_policy(MissingDepPolicy::Error);
+    StaticBuilder::new(InitStatic::symbol(&PLUGIN))
+        .depends_on(InitStatic::symbol(&BASE))
+        .init_async(|| async {
+            InitStatic::init(&PLUGIN, *BASE + 1);
+            Ok(())
+        });
+    register_init(InitStatic::symbol(&EXTRA), &[InitStatic::symbol(&DOUBLED)], || {
+        InitStatic::init(&EXTRA, *DOUBLED + 1);
+        Ok(())
+    });
+    init_static().await.unwrap();
+    assert_eq!(*PLUGIN, 2);
+    assert_eq!(*DOUBLED, 4);
+    assert_eq!(*EXTRA, 5);
+    let result = std::panic::catch_unwind(|| register_init(InitStatic::symbol(&EXTRA), &[], || Ok(())));
+    assert!(result.is_err());
+}
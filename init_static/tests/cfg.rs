@@ -0,0 +1,23 @@
+use init_static::{InitStatic, dependency_graph, init_static};
+
+init_static! {
+    #[cfg(feature = "serde")]
+    static METRICS: u32 = "1".parse()?;
+    static REPORT: u32 = {
+        #[cfg(feature = "serde")]
+        let metrics = *METRICS;
+        #[cfg(not(feature = "serde"))]
+        let metrics = 0;
+        metrics + 1
+    };
+}
+
+#[tokio::test]
+async fn main() {
+    let graph = dependency_graph();
+    let report = graph.iter().find(|(symbol, _)| symbol.ident == "REPORT").unwrap();
+    assert_eq!(report.1.len(), cfg!(feature = "serde") as usize);
+    init_static().await.unwrap();
+    assert_eq!(*REPORT, if cfg!(feature = "serde") { 2 } else { 1 });
+    assert!(InitStatic::is_set(&REPORT));
+}
@@ -0,0 +1,30 @@
+use init_static::{InitError, InitStatic, init_static, set_collect_errors};
+
+init_static! {
+    static FOO: u32 = "foo".parse()?;
+    static BAR: u32 = *FOO + 1;
+    static BAZ: u32 = async { "baz".parse() }.await?;
+    static QUX: u32 = "42".parse()?;
+    static QUUX: u32 = *BAR + *QUX;
+}
+
+#[tokio::test]
+async fn main() {
+    set_collect_errors(true);
+    let e = init_static().await.unwrap_err();
+    let Some(InitError::Multiple { errors, skipped }) = e.downcast_ref::<InitError>() else {
+        panic!("unexpected error: {e}");
+    };
+    let mut failed = errors.iter().map(|(symbol, _)| symbol.ident).collect::<Vec<_>>();
+    failed.sort();
+    assert_eq!(failed, ["BAZ", "FOO"]);
+    let mut skipped = skipped
+        .iter()
+        .map(|(symbol, because)| (symbol.ident, because.ident))
+        .collect::<Vec<_>>();
+    skipped.sort();
+    assert_eq!(skipped, [("BAR", "FOO"), ("QUUX", "FOO")]);
+    assert!(e.to_string().contains("::QUUX (at "));
+    assert_eq!(*QUX, 42);
+    assert!(!InitStatic::is_set(&BAR));
+}
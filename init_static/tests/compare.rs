@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+
+use init_static::{InitStatic, init_static};
+
+init_static! {
+    static LOW: u32 = "1".parse()?;
+    static HIGH: u32 = "2".parse()?;
+    static NAME: String = "low".to_owned();
+}
+
+#[tokio::test]
+#[allow(clippy::mutable_key_type)]
+async fn main() {
+    init_static().await.unwrap();
+    assert!(LOW == LOW);
+    assert!(LOW != HIGH);
+    assert!(LOW < HIGH);
+    assert_eq!(LOW.cmp(&HIGH), std::cmp::Ordering::Less);
+    assert_eq!(HIGH.partial_cmp(&LOW), Some(std::cmp::Ordering::Greater));
+
+    let mut map = HashMap::new();
+    map.insert(&NAME, 1);
+    map.insert(&NAME, 2);
+    assert_eq!(map.len(), 1);
+    assert_eq!(map[&NAME], 2);
+
+    // Keys are hashed and compared by value, not by symbol.
+    let other = InitStatic!(OTHER);
+    InitStatic::init(&other, "low".to_owned());
+    assert_eq!(map[&other], 2);
+}
+
+#[test]
+#[should_panic(expected = "access to uninitialized init_static")]
+fn uninitialized() {
+    let left: InitStatic<u32> = InitStatic!(LEFT);
+    let right: InitStatic<u32> = InitStatic!(RIGHT);
+    let _ = left == right;
+}
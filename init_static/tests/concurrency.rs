@@ -0,0 +1,29 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use init_static::{init_static, set_max_concurrency};
+
+static RUNNING: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+async fn track() -> u32 {
+    let running = RUNNING.fetch_add(1, Ordering::SeqCst) + 1;
+    PEAK.fetch_max(running, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(10)).await;
+    RUNNING.fetch_sub(1, Ordering::SeqCst);
+    0
+}
+
+init_static! {
+    static A: u32 = track().await;
+    static B: u32 = track().await;
+    static C: u32 = track().await;
+    static D: u32 = track().await;
+}
+
+#[tokio::test]
+async fn main() {
+    set_max_concurrency(2);
+    init_static().await.unwrap();
+    assert_eq!(PEAK.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,27 @@
+use init_static::{
+    InitStatic, MissingDepPolicy, dependency_graph, init_order, init_static, registered_count, registered_symbols,
+    set_missing_dep_policy,
+};
+
+init_static! {
+    static PORT: u16 = 8080;
+    static ADDRESS: String = format!("localhost:{}", *PORT);
+}
+
+#[tokio::test]
+async fn main() {
+    // `PORT` holds its value before initialization, and is not registered.
+    assert_eq!(*PORT, 8080);
+    assert_eq!(registered_count(), 1);
+    assert!(registered_symbols().all(|symbol| symbol.ident != "PORT"));
+    let order = init_order().unwrap();
+    assert!(order.iter().flatten().all(|symbol| symbol.ident != "PORT"));
+    let graph = dependency_graph();
+    assert_eq!(graph.len(), 1);
+    assert!(graph[0].1.is_empty());
+
+    set_missing_dep_policy(MissingDepPolicy::Error);
+    init_static().await.unwrap();
+    assert_eq!(*ADDRESS, "localhost:8080");
+    assert!(InitStatic::is_set(&PORT));
+}
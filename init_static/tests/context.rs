@@ -0,0 +1,20 @@
+use anyhow::Context;
+use init_static::init_static;
+
+init_static! {
+    static PORT: u16 = "http".parse().context("invalid port")?;
+}
+
+#[tokio::test]
+async fn main() {
+    let e = init_static().await.unwrap_err();
+    let chain = e.chain().map(|e| e.to_string()).collect::<Vec<_>>();
+    assert_eq!(
+        chain,
+        [
+            "failed to initialize context::PORT (at init_static/tests/context.rs:5:12)",
+            "invalid port",
+            "invalid digit found in string",
+        ]
+    );
+}
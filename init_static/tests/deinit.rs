@@ -0,0 +1,31 @@
+use std::sync::Mutex;
+
+use init_static::{deinit_static, init_static};
+
+static ORDER: Mutex<Vec<u32>> = Mutex::new(Vec::new());
+
+fn close(value: &u32) {
+    ORDER.lock().unwrap().push(*value);
+}
+
+async fn close_async(value: &u32) {
+    ORDER.lock().unwrap().push(*value);
+}
+
+init_static! {
+    #[deinit(close)]
+    static FOO: u32 = 1;
+    #[deinit(async close_async)]
+    static BAR: u32 = *FOO + 1;
+    #[deinit(close)]
+    static BAZ: u32 = *BAR + 1;
+}
+
+#[tokio::test]
+async fn main() {
+    init_static().await.unwrap();
+    deinit_static().await;
+    assert_eq!(*ORDER.lock().unwrap(), [3, 2, 1]);
+    deinit_static().await;
+    assert_eq!(ORDER.lock().unwrap().len(), 3);
+}
@@ -0,0 +1,25 @@
+use init_static::{InitStatic, Symbol, dep, init_static};
+
+init_static! {
+    static NAMES: Vec<String> = vec!["foo".to_string(), "bar".to_string()];
+    static COUNT: usize = dep!(NAMES).len();
+    static FIRST: String = async { dep!(NAMES)[0].clone() }.await;
+}
+
+static MISSING: InitStatic<u32> = InitStatic!(MISSING);
+
+#[tokio::test]
+async fn main() {
+    init_static().await.unwrap();
+    assert_eq!(*COUNT, 2);
+    assert_eq!(*FIRST, "foo");
+
+    let dependent = Symbol!(DEPENDENT);
+    let error = std::panic::catch_unwind(|| *InitStatic::expect(&MISSING, dependent)).unwrap_err();
+    let message = error.downcast_ref::<String>().unwrap();
+    assert!(message.contains("::DEPENDENT (at "), "{message}");
+    assert!(
+        message.contains("accessed its dependency dep::MISSING (at "),
+        "{message}"
+    );
+}
@@ -0,0 +1,30 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use init_static::{InitStatic, Symbol, init_order, init_static};
+
+static USE_FOO: AtomicBool = AtomicBool::new(false);
+
+fn bar_deps() -> Vec<Option<&'static Symbol>> {
+    match USE_FOO.load(Ordering::Relaxed) {
+        true => vec![Some(InitStatic::symbol(&FOO))],
+        false => vec![],
+    }
+}
+
+init_static! {
+    static FOO: u32 = *BAR + 1;
+    #[deps_fn(bar_deps)]
+    static BAR: u32 = match USE_FOO.load(Ordering::Relaxed) {
+        true => *FOO,
+        false => 1,
+    };
+}
+
+#[tokio::test]
+async fn main() {
+    USE_FOO.store(true, Ordering::Relaxed);
+    assert!(init_order().is_err());
+    USE_FOO.store(false, Ordering::Relaxed);
+    init_static().await.unwrap();
+    assert_eq!(*FOO, 2);
+}
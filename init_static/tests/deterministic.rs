@@ -0,0 +1,24 @@
+use std::sync::Mutex;
+
+use init_static::{init_static, set_deterministic};
+
+static ORDER: Mutex<Vec<&str>> = Mutex::new(Vec::new());
+
+fn record(name: &'static str) -> u32 {
+    ORDER.lock().unwrap().push(name);
+    0
+}
+
+init_static! {
+    static D: u32 = async { record("D") }.await;
+    static C: u32 = record("C");
+    static B: u32 = async { record("B") }.await;
+    static A: u32 = record("A") + *B;
+}
+
+#[tokio::test]
+async fn main() {
+    set_deterministic(true);
+    init_static().await.unwrap();
+    assert_eq!(*ORDER.lock().unwrap(), ["C", "D", "B", "A"]);
+}
@@ -0,0 +1,37 @@
+use std::sync::Mutex;
+
+use init_static::{InitStatic, deinit_static, init_static};
+
+static ORDER: Mutex<Vec<&str>> = Mutex::new(Vec::new());
+
+struct Flush(&'static str);
+
+impl Drop for Flush {
+    fn drop(&mut self) {
+        ORDER.lock().unwrap().push(self.0);
+    }
+}
+
+fn close(_: &Flush) {
+    ORDER.lock().unwrap().push("close");
+}
+
+init_static! {
+    #[deinit(close)]
+    #[drop_on_deinit]
+    static mut LOGGER: Flush = Flush("logger");
+    #[drop_on_deinit]
+    #[depends(LOGGER)]
+    static mut CACHE: Flush = Flush("cache");
+    #[depends(CACHE)]
+    static SERVER: Flush = Flush("server");
+}
+
+#[tokio::test]
+async fn main() {
+    init_static().await.unwrap();
+    deinit_static().await;
+    assert_eq!(*ORDER.lock().unwrap(), ["cache", "close", "logger"]);
+    assert!(InitStatic::is_set(&SERVER));
+    assert!(!InitStatic::is_set(unsafe { &*std::ptr::addr_of!(LOGGER) }));
+}
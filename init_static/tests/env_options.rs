@@ -0,0 +1,22 @@
+use std::time::Duration;
+
+use init_static::{InitError, init_static};
+
+init_static! {
+    static FOO: u32 = async {
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        42
+    }
+    .await;
+}
+
+#[tokio::test]
+async fn main() {
+    // SAFETY: no other thread reads the environment at this point.
+    unsafe { std::env::set_var("INIT_STATIC_TIMEOUT_MS", "50") };
+    let e = init_static().await.unwrap_err();
+    assert!(matches!(
+        e.downcast_ref::<InitError>(),
+        Some(InitError::Timeout { symbol, elapsed }) if symbol.ident == "FOO" && *elapsed < Duration::from_secs(1)
+    ));
+}
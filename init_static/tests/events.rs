@@ -0,0 +1,39 @@
+use futures_util::StreamExt;
+use init_static::{InitEvent, init_static, init_static_events};
+
+init_static! {
+    static A: u32 = "1".parse()?;
+    static B: u32 = async { *A + 1 }.await;
+}
+
+#[tokio::test]
+async fn main() {
+    let (events, init) = init_static_events();
+    let handle = tokio::spawn(init);
+    let events = events.collect::<Vec<_>>().await;
+    handle.await.unwrap().unwrap();
+    assert_eq!(*B, 2);
+    let events = events
+        .into_iter()
+        .map(|event| match event {
+            InitEvent::Started { total } => format!("started {total}"),
+            InitEvent::SyncBegin { symbol } => format!("sync begin {}", symbol.ident),
+            InitEvent::SyncEnd { symbol } => format!("sync end {}", symbol.ident),
+            InitEvent::AsyncBegin { symbol } => format!("async begin {}", symbol.ident),
+            InitEvent::AsyncEnd { symbol } => format!("async end {}", symbol.ident),
+            InitEvent::Progress { symbol, .. } => format!("progress {}", symbol.ident),
+            InitEvent::Finished => "finished".to_owned(),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        events,
+        [
+            "started 2",
+            "sync begin A",
+            "sync end A",
+            "async begin B",
+            "async end B",
+            "finished"
+        ]
+    );
+}
@@ -0,0 +1,24 @@
+#![cfg(not(feature = "anyhow"))]
+
+use std::num::ParseIntError;
+
+use init_static::{InitError, init_static};
+
+init_static! {
+    static FOO: u32 = "malformed".parse()?;
+}
+
+#[tokio::test]
+async fn main() {
+    let e = init_static().await.unwrap_err();
+    let Some(InitError::Execution { symbol, source }) = e.downcast_ref::<InitError>() else {
+        panic!("unexpected error: {e:?}");
+    };
+    assert_eq!(symbol.ident, "FOO");
+    assert!(source.downcast_ref::<ParseIntError>().is_some());
+    assert_eq!(
+        e.to_string(),
+        "Failed to initialize execution::FOO (at init_static/tests/execution.rs:8:12)."
+    );
+    assert!(e.source().unwrap().is::<ParseIntError>());
+}
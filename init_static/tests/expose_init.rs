@@ -0,0 +1,22 @@
+use init_static::{InitStatic, init_static};
+
+init_static! {
+    #[expose_init]
+    static BASE: u32 = "41".parse()?;
+    #[expose_init]
+    static VALUE: u32 = async { *BASE + 1 }.await;
+    static OTHER: u32 = "1".parse()?;
+}
+
+#[tokio::test]
+async fn main() {
+    init_BASE().unwrap();
+    init_VALUE().await.unwrap();
+    assert_eq!(*VALUE, 42);
+    assert!(!InitStatic::is_set(&OTHER));
+
+    // Already initialized statics are skipped.
+    init_BASE().unwrap();
+    init_static().await.unwrap();
+    assert_eq!(*OTHER, 1);
+}
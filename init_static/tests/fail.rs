@@ -1,3 +1,5 @@
+use std::num::ParseIntError;
+
 use init_static::init_static;
 
 init_static! {
@@ -9,6 +11,12 @@ async fn main() {
     let e = init_static().await.unwrap_err();
     assert_eq!(
         e.to_string(),
-        "failed to initialize FOO (at init_static/tests/fail.rs:4:12)"
+        "failed to initialize fail::FOO (at init_static/tests/fail.rs:6:12)"
+    );
+    assert_eq!(
+        format!("{e:#}"),
+        "failed to initialize fail::FOO (at init_static/tests/fail.rs:6:12): invalid digit found in string"
     );
+    assert!(e.root_cause().downcast_ref::<ParseIntError>().is_some());
+    assert!(e.source().unwrap().is::<ParseIntError>());
 }
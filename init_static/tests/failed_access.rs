@@ -0,0 +1,28 @@
+use std::panic::catch_unwind;
+
+use init_static::{init_static, set_collect_errors};
+
+init_static! {
+    static FOO: u32 = "malformed".parse()?;
+    static BAR: u32 = *FOO + 1;
+}
+
+fn panic_message(f: impl FnOnce() + std::panic::UnwindSafe) -> String {
+    let payload = catch_unwind(f).unwrap_err();
+    payload.downcast_ref::<String>().cloned().unwrap()
+}
+
+#[tokio::test]
+async fn main() {
+    set_collect_errors(true);
+    init_static().await.unwrap_err();
+    assert_eq!(
+        panic_message(|| println!("{}", *FOO)),
+        "init of failed_access::FOO (at init_static/tests/failed_access.rs:6:12) failed: invalid digit found in string"
+    );
+    assert_eq!(
+        panic_message(|| println!("{}", *BAR)),
+        "init of failed_access::BAR (at init_static/tests/failed_access.rs:7:12) was skipped because \
+         failed_access::FOO (at init_static/tests/failed_access.rs:6:12) failed"
+    );
+}
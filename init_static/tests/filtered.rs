@@ -0,0 +1,36 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use init_static::{InitStatic, init_static, init_static_filtered};
+
+static FAIL: AtomicBool = AtomicBool::new(true);
+
+init_static! {
+    static ROOT: u32 = "1".parse()?;
+    static MID: u32 = *ROOT + 1;
+    static TOP: u32 = *MID + 1;
+    static OTHER: u32 = *ROOT * 10;
+    static BROKEN: u32 = if FAIL.load(Ordering::SeqCst) { "oops" } else { "5" }.parse()?;
+    static AFTER_BROKEN: u32 = *BROKEN + 1;
+}
+
+#[tokio::test]
+async fn main() {
+    // An error inside the selection fails the call, and leaves the statics outside it untouched.
+    assert!(
+        init_static_filtered(|symbol| symbol.ident == "AFTER_BROKEN")
+            .await
+            .is_err()
+    );
+    assert!(!InitStatic::is_set(&BROKEN));
+    assert!(!InitStatic::is_set(&AFTER_BROKEN));
+    assert!(!InitStatic::is_set(&ROOT));
+
+    // The selection pulls in its transitive dependencies, but not the other dependents of a shared
+    // dependency.
+    FAIL.store(false, Ordering::SeqCst);
+    init_static_filtered(|symbol| symbol.ident == "TOP").await.unwrap();
+    assert_eq!((*ROOT, *MID, *TOP), (1, 2, 3));
+    assert!(!InitStatic::is_set(&OTHER));
+    assert!(!InitStatic::is_set(&BROKEN));
+    assert!(!InitStatic::is_set(&AFTER_BROKEN));
+}
@@ -0,0 +1,37 @@
+use init_static::{dependency_graph, dependency_graph_dot, init_static};
+
+fn must_not_run() -> u32 {
+    panic!("initializers must not run")
+}
+
+init_static! {
+    static FOO: u32 = *BAR + *BAZ + u32::MAX;
+    static BAR: u32 = *BAZ;
+    static BAZ: u32 = must_not_run();
+}
+
+#[test]
+fn main() {
+    let graph = dependency_graph()
+        .into_iter()
+        .map(|(symbol, deps)| (symbol.ident, deps.iter().map(|dep| dep.ident).collect::<Vec<_>>()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        graph,
+        [("FOO", vec!["BAR", "BAZ"]), ("BAR", vec!["BAZ"]), ("BAZ", vec![])]
+    );
+    assert_eq!(
+        dependency_graph_dot(),
+        [
+            "digraph init_static {\n",
+            "    \"graph::FOO\";\n",
+            "    \"graph::FOO\" -> \"graph::BAR\";\n",
+            "    \"graph::FOO\" -> \"graph::BAZ\";\n",
+            "    \"graph::BAR\";\n",
+            "    \"graph::BAR\" -> \"graph::BAZ\";\n",
+            "    \"graph::BAZ\";\n",
+            "}\n",
+        ]
+        .join("")
+    );
+}
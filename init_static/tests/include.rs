@@ -0,0 +1,19 @@
+use init_static::init_static;
+
+// The same statics are declared in two modules, so their generated items share the same names.
+mod a {
+    include!("include/statics.rs");
+}
+
+mod b {
+    include!("include/statics.rs");
+}
+
+#[tokio::test]
+async fn main() {
+    init_static().await.unwrap();
+    assert_eq!(*a::VALUE, "include::a");
+    assert_eq!(*b::VALUE, "include::b");
+    assert_eq!(*a::LEN, 10);
+    assert_eq!(*b::LEN, 10);
+}
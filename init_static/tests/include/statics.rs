@@ -0,0 +1,4 @@
+init_static::init_static! {
+    pub static VALUE: String = module_path!().to_owned();
+    pub static LEN: usize = VALUE.len();
+}
@@ -0,0 +1,29 @@
+use init_static::{InitError, init_static, init_static_with};
+
+struct Config {
+    host: &'static str,
+    port: u16,
+}
+
+init_static! {
+    #[context(config: Config)]
+    static PORT: u16 = config.port;
+    #[context(config: Config)]
+    static ADDR: String = async { format!("{}:{}", config.host, *PORT) }.await;
+}
+
+#[tokio::test]
+async fn main() {
+    let e = init_static().await.unwrap_err();
+    assert!(matches!(
+        e.downcast_ref::<InitError>(),
+        Some(InitError::MissingContext { symbol, type_name }) if symbol.ident == "PORT" && type_name.ends_with("Config")
+    ));
+
+    let config = Config {
+        host: "localhost",
+        port: 8080,
+    };
+    init_static_with(config).await.unwrap();
+    assert_eq!(*ADDR, "localhost:8080");
+}
@@ -0,0 +1,23 @@
+use init_static::{InitStatic, init_static};
+
+init_static! {
+    static LIST: Vec<u32> = Vec::from(["1".parse()?, 2, 3]);
+}
+
+#[tokio::test]
+async fn main() {
+    init_static().await.unwrap();
+    let mut sum = 0;
+    for value in &LIST {
+        sum += value;
+    }
+    assert_eq!(sum, 6);
+    assert_eq!((&LIST).into_iter().count(), 3);
+
+    let mut list = InitStatic!(LOCAL);
+    InitStatic::init(&list, vec![1, 2, 3]);
+    for value in &mut list {
+        *value *= 10;
+    }
+    assert_eq!(*list, [10, 20, 30]);
+}
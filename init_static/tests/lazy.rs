@@ -0,0 +1,24 @@
+use std::sync::{LazyLock, Mutex};
+
+use init_static::{InitStatic, init_static};
+
+static ORDER: Mutex<Vec<&str>> = Mutex::new(Vec::new());
+
+static LAZY: LazyLock<u32> = LazyLock::new(|| {
+    ORDER.lock().unwrap().push("LAZY");
+    42
+});
+
+init_static! {
+    static VALUE: u32 = async {
+        ORDER.lock().unwrap().push("VALUE");
+        *LAZY + 1
+    }.await;
+}
+
+#[tokio::test]
+async fn main() {
+    init_static().await.unwrap();
+    assert_eq!(*ORDER.lock().unwrap(), ["LAZY", "VALUE"]);
+    assert_eq!(InitStatic::get(&VALUE), Some(&43));
+}
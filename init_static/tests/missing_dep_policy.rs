@@ -0,0 +1,26 @@
+use init_static::{InitError, InitStatic, MissingDepPolicy, init_static, set_missing_dep_policy, validate_graph};
+
+static MANUAL: InitStatic<u32> = InitStatic!(MANUAL);
+
+init_static! {
+    static VALUE: u32 = *MANUAL + 1;
+}
+
+#[tokio::test]
+async fn main() {
+    InitStatic::init(&MANUAL, 41);
+
+    set_missing_dep_policy(MissingDepPolicy::Error);
+    assert!(matches!(
+        validate_graph(),
+        Err(InitError::MissingDependency { dependent, missing })
+            if dependent.ident == "VALUE" && missing.ident == "MANUAL"
+    ));
+
+    set_missing_dep_policy(MissingDepPolicy::Ignore);
+    validate_graph().unwrap();
+
+    set_missing_dep_policy(MissingDepPolicy::Warn);
+    init_static().await.unwrap();
+    assert_eq!(*VALUE, 42);
+}
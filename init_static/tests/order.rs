@@ -0,0 +1,22 @@
+use init_static::{init_order, init_static};
+
+init_static! {
+    static C: u32 = *A + *B;
+    static A: u32 = "1".parse()?;
+    static B: u32 = *A + 1;
+    static D: u32 = "4".parse()?;
+}
+
+#[tokio::test]
+async fn main() {
+    let order = init_order().unwrap();
+    let names = order
+        .iter()
+        .map(|layer| layer.iter().map(|symbol| symbol.ident).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(names, [vec!["A", "D"], vec!["B"], vec!["C"]]);
+
+    init_static().await.unwrap();
+    assert_eq!(init_order().unwrap(), order);
+    assert_eq!(*C + *D, 7);
+}
@@ -0,0 +1,31 @@
+use std::sync::Mutex;
+
+use init_static::{InitError, InitStatic, init_static, init_static_ordered};
+
+static LOG: Mutex<Vec<&str>> = Mutex::new(Vec::new());
+
+init_static! {
+    static A: u32 = { LOG.lock().unwrap().push("A"); 1 };
+    static B: u32 = { LOG.lock().unwrap().push("B"); 2 };
+    static C: u32 = { LOG.lock().unwrap().push("C"); *A + 2 };
+    static D: u32 = { LOG.lock().unwrap().push("D"); 4 };
+}
+
+#[tokio::test]
+async fn main() {
+    let e = init_static_ordered(&[InitStatic::symbol(&C), InitStatic::symbol(&A)])
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        e.downcast_ref::<InitError>(),
+        Some(InitError::OrderViolation { symbol, dependency })
+            if symbol.ident == "C" && dependency.ident == "A"
+    ));
+    assert!(LOG.lock().unwrap().is_empty());
+
+    let order = [&B, &A, &C].map(InitStatic::symbol);
+    init_static_ordered(&order).await.unwrap();
+    assert_eq!(*LOG.lock().unwrap(), ["B", "A", "C"]);
+    assert_eq!(*C, 3);
+    assert!(!InitStatic::is_set(&D));
+}
@@ -0,0 +1,17 @@
+use init_static::{InitOutcome, InitStatic, init_outcome, init_static};
+
+init_static! {
+    static FOO: u32 = "foo".parse()?;
+    static BAR: u32 = *FOO + 1;
+}
+
+#[tokio::test]
+async fn main() {
+    assert_eq!(init_outcome(InitStatic::symbol(&FOO)), None);
+    init_static().await.unwrap_err();
+    assert_eq!(
+        init_outcome(InitStatic::symbol(&FOO)),
+        Some(InitOutcome::Failed("invalid digit found in string".to_owned()))
+    );
+    assert_eq!(init_outcome(InitStatic::symbol(&BAR)), None);
+}
@@ -0,0 +1,32 @@
+use init_static::{InitError, InitStatic, init_static, set_catch_panics, set_collect_errors};
+
+fn explode(message: &str) -> u32 {
+    panic!("{message}")
+}
+
+init_static! {
+    static FOO: u32 = explode("foo exploded");
+    static BAR: u32 = async { explode("bar exploded") }.await;
+    static BAZ: u32 = 42;
+}
+
+#[tokio::test]
+async fn main() {
+    set_catch_panics(true);
+    set_collect_errors(true);
+    let e = init_static().await.unwrap_err();
+    let Some(InitError::Multiple { errors, .. }) = e.downcast_ref::<InitError>() else {
+        panic!("unexpected error: {e}");
+    };
+    let mut panics = errors
+        .iter()
+        .map(|(_, e)| match e.downcast_ref::<InitError>() {
+            Some(InitError::Panic { symbol, message }) => (symbol.ident, message.as_str()),
+            _ => panic!("unexpected error: {e}"),
+        })
+        .collect::<Vec<_>>();
+    panics.sort();
+    assert_eq!(panics, [("BAR", "bar exploded"), ("FOO", "foo exploded")]);
+    assert_eq!(*BAZ, 42);
+    assert!(!InitStatic::is_set(&FOO));
+}
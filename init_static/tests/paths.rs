@@ -0,0 +1,37 @@
+use init_static::{dependency_graph, init_static};
+
+// Stands in for another crate, whose statics are only reachable through paths and re-exports.
+mod other {
+    mod db {
+        init_static::init_static! {
+            pub static URL: String = "postgres://localhost".to_owned();
+        }
+
+        pub fn url() -> &'static str {
+            &URL
+        }
+    }
+
+    pub use db::{URL, url};
+}
+
+init_static! {
+    static POOL: String = format!("pool({})", *other::URL);
+    #[depends(crate::other::URL)]
+    static MIRROR: String = other::url().to_owned();
+}
+
+#[tokio::test]
+async fn main() {
+    let graph = dependency_graph();
+    for ident in ["POOL", "MIRROR"] {
+        let (_, deps) = graph.iter().find(|(symbol, _)| symbol.ident == ident).unwrap();
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].ident, "URL");
+        assert!(deps[0].module.ends_with("::other::db"));
+    }
+
+    init_static().await.unwrap();
+    assert_eq!(*POOL, "pool(postgres://localhost)");
+    assert_eq!(*MIRROR, "postgres://localhost");
+}
@@ -0,0 +1,29 @@
+use std::sync::Mutex;
+
+use init_static::{init_static, set_max_concurrency, set_prioritize_dependents};
+
+static STARTED: Mutex<Vec<&str>> = Mutex::new(Vec::new());
+
+async fn start(name: &'static str) -> u32 {
+    STARTED.lock().unwrap().push(name);
+    tokio::task::yield_now().await;
+    1
+}
+
+init_static! {
+    static A: u32 = start("A").await;
+    static B: u32 = start("B").await;
+    static C: u32 = start("C").await;
+    static D: u32 = *C + 1;
+    static E: u32 = *C + *B;
+    static F: u32 = *C + *E;
+}
+
+#[tokio::test]
+async fn main() {
+    set_max_concurrency(1);
+    set_prioritize_dependents(true);
+    init_static().await.unwrap();
+    assert_eq!(STARTED.lock().unwrap()[..2], ["C", "B"]);
+    assert_eq!(*A + *D + *F, 6);
+}
@@ -0,0 +1,23 @@
+use std::time::Duration;
+
+use init_static::{init_static, last_init_report, set_profile};
+
+init_static! {
+    static FOO: u32 = async {
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        *BAR
+    }
+    .await;
+    static BAR: u32 = "42".parse()?;
+}
+
+#[tokio::test]
+async fn main() {
+    set_profile(true);
+    init_static().await.unwrap();
+    let report = last_init_report();
+    assert_eq!(report.len(), 2);
+    assert_eq!(report[0].0.ident, "BAR");
+    assert_eq!(report[1].0.ident, "FOO");
+    assert!(report[1].1 >= Duration::from_millis(20));
+}
@@ -0,0 +1,29 @@
+use std::sync::Mutex;
+
+use init_static::{InitEvent, InitStatic, init_static, set_progress_hook};
+
+static EVENTS: Mutex<Vec<InitEvent>> = Mutex::new(Vec::new());
+
+init_static! {
+    static FOO: u32 = async { *BAR + 1 }.await;
+    static BAR: u32 = "42".parse()?;
+}
+
+#[tokio::test]
+async fn main() {
+    set_progress_hook(|event| EVENTS.lock().unwrap().push(event));
+    init_static().await.unwrap();
+    let foo = InitStatic::symbol(&FOO);
+    let bar = InitStatic::symbol(&BAR);
+    assert_eq!(
+        *EVENTS.lock().unwrap(),
+        [
+            InitEvent::Started { total: 2 },
+            InitEvent::SyncBegin { symbol: bar },
+            InitEvent::SyncEnd { symbol: bar },
+            InitEvent::AsyncBegin { symbol: foo },
+            InitEvent::AsyncEnd { symbol: foo },
+            InitEvent::Finished,
+        ]
+    );
+}
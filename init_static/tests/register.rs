@@ -0,0 +1,17 @@
+use init_static::{init_static, register};
+
+#[register]
+static BASE: u32 = "42".parse()?;
+
+#[register]
+static DERIVED: u32 = async { *BASE + 1 }.await;
+
+init_static! {
+    static LIST: Vec<u32> = Vec::from([*BASE, *DERIVED]);
+}
+
+#[tokio::test]
+async fn main() {
+    init_static().await.unwrap();
+    assert_eq!(*LIST, [42, 43]);
+}
@@ -0,0 +1,18 @@
+use init_static::{init_static, init_static_report};
+
+init_static! {
+    static FOO: u32 = *BAR + 1;
+    static BAR: u32 = async { 1 }.await;
+    static BAZ: u32 = *FOO + *BAR;
+}
+
+#[tokio::test]
+async fn main() {
+    let report = init_static_report().await.unwrap();
+    assert_eq!(report.total, 3);
+    assert_eq!(report.sync_count, 2);
+    assert_eq!(report.async_count, 1);
+    let order = report.order.iter().map(|symbol| symbol.ident).collect::<Vec<_>>();
+    assert_eq!(order, ["BAR", "FOO", "BAZ"]);
+    assert_eq!(*BAZ, 3);
+}
@@ -0,0 +1,46 @@
+use std::sync::Mutex;
+
+use init_static::{InitEvent, InitStatic, init_static, report_progress, set_progress_hook};
+
+static PROGRESS: Mutex<Vec<(&str, f64)>> = Mutex::new(Vec::new());
+
+fn load(len: u32) -> u32 {
+    for i in 1..=len {
+        report_progress(i as f64 / len as f64);
+    }
+    len
+}
+
+init_static! {
+    static SYNC: u32 = load(2);
+    static ASYNC: u32 = async {
+        let value = load(4);
+        tokio::task::yield_now().await;
+        value + *SYNC
+    }.await;
+}
+
+#[tokio::test]
+async fn main() {
+    set_progress_hook(|event| {
+        if let InitEvent::Progress { symbol, progress } = event {
+            PROGRESS.lock().unwrap().push((symbol.ident, progress));
+        }
+    });
+    report_progress(0.5);
+    init_static().await.unwrap();
+    report_progress(0.5);
+    assert_eq!(*ASYNC, 6);
+    assert!(InitStatic::is_set(&SYNC));
+    assert_eq!(
+        *PROGRESS.lock().unwrap(),
+        [
+            ("SYNC", 0.5),
+            ("SYNC", 1.0),
+            ("ASYNC", 0.25),
+            ("ASYNC", 0.5),
+            ("ASYNC", 0.75),
+            ("ASYNC", 1.0),
+        ]
+    );
+}
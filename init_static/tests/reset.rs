@@ -0,0 +1,17 @@
+use init_static::{InitStatic, init_static, is_initialized, reset_init_options};
+
+#[tokio::test]
+async fn main() {
+    let mut value = InitStatic!(VALUE);
+    InitStatic::init(&value, 42);
+    assert_eq!(InitStatic::reset(&mut value), Some(42));
+    assert_eq!(InitStatic::get(&value), None);
+    InitStatic::init(&value, 43);
+    assert_eq!(*value, 43);
+
+    init_static().await.unwrap();
+    assert!(is_initialized());
+    reset_init_options();
+    assert!(!is_initialized());
+    init_static().await.unwrap();
+}
@@ -0,0 +1,29 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use init_static::{
+    InitOutcome, InitStatic, init_outcome, init_static, last_init_report, reset_init_options, set_profile,
+};
+
+static FAIL: AtomicBool = AtomicBool::new(true);
+
+init_static! {
+    static VALUE: u32 = if FAIL.load(Ordering::SeqCst) { "oops" } else { "42" }.parse()?;
+}
+
+#[tokio::test]
+async fn main() {
+    let symbol = InitStatic::symbol(&VALUE);
+    set_profile(true);
+    assert!(init_static().await.is_err());
+    assert!(matches!(init_outcome(symbol), Some(InitOutcome::Failed(_))));
+    assert_eq!(last_init_report().len(), 1);
+
+    reset_init_options();
+    assert_eq!(init_outcome(symbol), None);
+    assert!(last_init_report().is_empty());
+
+    FAIL.store(false, Ordering::SeqCst);
+    init_static().await.unwrap();
+    assert_eq!(init_outcome(symbol), Some(InitOutcome::Ok));
+    assert_eq!(*VALUE, 42);
+}
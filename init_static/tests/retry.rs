@@ -0,0 +1,26 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use anyhow::ensure;
+use init_static::{init_static, is_initialized};
+
+static ATTEMPTS: AtomicU32 = AtomicU32::new(0);
+static BAR_RUNS: AtomicU32 = AtomicU32::new(0);
+
+init_static! {
+    static FOO: u32 = {
+        let attempt = ATTEMPTS.fetch_add(1, Ordering::SeqCst) + 1;
+        ensure!(attempt > 1, "transient failure");
+        *BAR + attempt
+    };
+    static BAR: u32 = BAR_RUNS.fetch_add(1, Ordering::SeqCst) + 42;
+}
+
+#[tokio::test]
+async fn main() {
+    init_static().await.unwrap_err();
+    assert!(!is_initialized());
+    init_static().await.unwrap();
+    assert!(is_initialized());
+    assert_eq!(*FOO, 44);
+    assert_eq!(BAR_RUNS.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,30 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::Duration;
+
+use anyhow::ensure;
+use init_static::{init_static, set_retry};
+
+static FOO_ATTEMPTS: AtomicU32 = AtomicU32::new(0);
+static BAR_ATTEMPTS: AtomicU32 = AtomicU32::new(0);
+
+init_static! {
+    static FOO: u32 = async {
+        let attempt = FOO_ATTEMPTS.fetch_add(1, Ordering::SeqCst) + 1;
+        ensure!(attempt == 3, "transient failure");
+        Ok(attempt)
+    }
+    .await?;
+    static BAR: u32 = {
+        let attempt = BAR_ATTEMPTS.fetch_add(1, Ordering::SeqCst) + 1;
+        ensure!(attempt == 2, "transient failure");
+        attempt
+    };
+}
+
+#[tokio::test]
+async fn main() {
+    set_retry(3, Duration::from_millis(1));
+    init_static().await.unwrap();
+    assert_eq!(*FOO, 3);
+    assert_eq!(*BAR, 2);
+}
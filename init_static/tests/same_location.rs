@@ -0,0 +1,21 @@
+use init_static::{InitStatic, init_static};
+
+macro_rules! generate {
+    () => {
+        init_static! {
+            static FOO: u32 = "1".parse()?;
+            static BAR: u32 = *FOO + 1;
+        }
+    };
+}
+
+generate!();
+
+#[tokio::test]
+async fn main() {
+    let (foo, bar) = (InitStatic::symbol(&FOO), InitStatic::symbol(&BAR));
+    assert_eq!((foo.file, foo.line, foo.column), (bar.file, bar.line, bar.column));
+    assert_ne!(foo, bar);
+    init_static().await.unwrap();
+    assert_eq!(*BAR, 2);
+}
@@ -0,0 +1,19 @@
+use init_static::{InitError, init_static};
+
+#[tokio::test]
+async fn main() {
+    init_static! {
+        static FOO: u32 = 1;
+        static BAR: u32 = *BAR + *FOO;
+    }
+
+    let e = init_static().await.unwrap_err();
+    assert!(matches!(
+        e.downcast_ref::<InitError>(),
+        Some(InitError::SelfDependency { symbol }) if symbol.ident == "BAR"
+    ));
+    assert_eq!(
+        e.to_string(),
+        "Static self_dependency::BAR (at init_static/tests/self_dependency.rs:7:16) depends on itself."
+    );
+}
@@ -0,0 +1,14 @@
+use init_static::{InitStatic, init_static};
+
+init_static! {
+    static FOO: Vec<u32> = vec![1, 2, 3];
+}
+
+#[tokio::test]
+async fn main() {
+    let bar: InitStatic<u32> = InitStatic!(BAR);
+    assert!(serde_json::to_string(&bar).is_err());
+
+    init_static().await.unwrap();
+    assert_eq!(serde_json::to_string(&FOO).unwrap(), "[1,2,3]");
+}
@@ -0,0 +1,24 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread::{ThreadId, current};
+
+use init_static::{init_static, set_spawner};
+
+static SPAWNED: AtomicUsize = AtomicUsize::new(0);
+
+init_static! {
+    static BASE: u32 = 42;
+    static THREAD: ThreadId = async { current().id() }.await;
+    static VALUE: u32 = async { *BASE + 1 }.await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn main() {
+    set_spawner(|future| {
+        SPAWNED.fetch_add(1, Ordering::SeqCst);
+        Box::pin(async { tokio::spawn(future).await? })
+    });
+    init_static().await.unwrap();
+    assert_eq!(SPAWNED.load(Ordering::SeqCst), 2);
+    assert_ne!(*THREAD, current().id());
+    assert_eq!(*VALUE, 43);
+}
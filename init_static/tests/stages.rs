@@ -0,0 +1,41 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use init_static::init_static_stages;
+
+static ORDER: Mutex<Vec<&str>> = Mutex::new(Vec::new());
+
+fn record(name: &'static str) -> u32 {
+    ORDER.lock().unwrap().push(name);
+    0
+}
+
+mod config {
+    use super::*;
+
+    init_static::init_static! {
+        static URL: u32 = async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            record("URL")
+        }
+        .await;
+    }
+}
+
+mod db {
+    use super::*;
+
+    init_static::init_static! {
+        static POOL: u32 = record("POOL");
+    }
+}
+
+init_static::init_static! {
+    static APP: u32 = record("APP");
+}
+
+#[tokio::test]
+async fn main() {
+    init_static_stages(&["stages::config", "stages::db"]).await.unwrap();
+    assert_eq!(*ORDER.lock().unwrap(), ["URL", "POOL", "APP"]);
+}
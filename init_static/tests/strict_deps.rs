@@ -0,0 +1,25 @@
+use init_static::{InitError, InitStatic, init_static, set_strict_deps};
+
+static MANUAL: InitStatic<u32> = InitStatic!(MANUAL);
+
+init_static! {
+    static VALUE: u32 = *MANUAL + 1;
+}
+
+#[tokio::test]
+async fn main() {
+    InitStatic::init(&MANUAL, 41);
+
+    set_strict_deps(true);
+    let e = init_static().await.unwrap_err();
+    assert!(matches!(
+        e.downcast_ref::<InitError>(),
+        Some(InitError::MissingDependency { dependent, missing })
+            if dependent.ident == "VALUE" && missing.ident == "MANUAL"
+    ));
+    assert!(!InitStatic::is_set(&VALUE));
+
+    set_strict_deps(false);
+    init_static().await.unwrap();
+    assert_eq!(*VALUE, 42);
+}
@@ -0,0 +1,26 @@
+use init_static::{InitStatic, Symbol, init_static};
+
+macro_rules! counters {
+    ($($name:ident = $value:expr),*) => {
+        $(
+            fn $name() -> (&'static Symbol, u32) {
+                init_static! {
+                    static VALUE: u32 = $value;
+                }
+                (InitStatic::symbol(&VALUE), *VALUE)
+            }
+        )*
+    };
+}
+
+counters!(first = 1, second = 2);
+
+#[tokio::test]
+async fn main() {
+    init_static().await.unwrap();
+    let (first_symbol, first_value) = first();
+    let (second_symbol, second_value) = second();
+    assert_eq!(first_symbol, second_symbol);
+    assert_ne!(first_symbol.key(), second_symbol.key());
+    assert_eq!((first_value, second_value), (1, 2));
+}
@@ -0,0 +1,17 @@
+use init_static::{InitError, InitStatic, init_static, init_static_sync};
+
+init_static! {
+    static FOO: u32 = *BAR + 1;
+    static BAR: u32 = "42".parse()?;
+    static BAZ: u32 = async { 42 }.await;
+}
+
+#[test]
+fn main() {
+    let e = init_static_sync().unwrap_err();
+    assert!(matches!(
+        e.downcast_ref::<InitError>(),
+        Some(InitError::AsyncInNonAsyncContext { symbol }) if symbol.ident == "BAZ"
+    ));
+    assert_eq!(InitStatic::get(&FOO), None);
+}
@@ -0,0 +1,27 @@
+use std::cell::Cell;
+use std::thread;
+
+use init_static::{InitThreadLocal, init_thread_local};
+
+init_thread_local! {
+    static BUFFER: Vec<u8> = Vec::with_capacity(CAPACITY.with(|capacity| **capacity));
+    static CAPACITY: usize = COUNTER.with(|counter| counter.replace(counter.get() + 1)) + 1024;
+    static COUNTER: Cell<usize> = Cell::new(0);
+}
+
+#[test]
+fn main() {
+    init_thread_local().unwrap();
+    assert!(BUFFER.with(|buffer| buffer.capacity()) >= 1024);
+    init_thread_local().unwrap();
+    assert_eq!(CAPACITY.with(|capacity| **capacity), 1024);
+
+    thread::spawn(|| {
+        assert!(!BUFFER.with(InitThreadLocal::is_set));
+        init_thread_local().unwrap();
+        assert_eq!(COUNTER.with(|counter| counter.get()), 1);
+        assert_eq!(CAPACITY.with(|capacity| **capacity), 1024);
+    })
+    .join()
+    .unwrap();
+}
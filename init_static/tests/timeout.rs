@@ -0,0 +1,21 @@
+use std::time::Duration;
+
+use init_static::{InitError, init_static, set_timeout};
+
+init_static! {
+    static FOO: u32 = async {
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        42
+    }
+    .await;
+}
+
+#[tokio::test]
+async fn main() {
+    set_timeout(Duration::from_millis(50));
+    let e = init_static().await.unwrap_err();
+    assert!(matches!(
+        e.downcast_ref::<InitError>(),
+        Some(InitError::Timeout { symbol, .. }) if symbol.ident == "FOO"
+    ));
+}
@@ -0,0 +1,17 @@
+use std::time::Duration;
+
+use init_static::{init_static, init_static_on};
+
+init_static! {
+    static VALUE: u32 = tokio::task::spawn(async {
+        tokio::time::sleep(Duration::from_millis(1)).await;
+        42
+    }).await?;
+}
+
+#[test]
+fn main() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    init_static_on(runtime.handle()).unwrap();
+    assert_eq!(*VALUE, 42);
+}
@@ -0,0 +1,23 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use init_static::{InitStatic, init_static};
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+async fn setup() -> (String, u16) {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    ("localhost".to_string(), 8080)
+}
+
+init_static! {
+    static (HOST, PORT): (String, u16) = async { setup().await }.await;
+    static ADDR: String = format!("{}:{}", *HOST, *PORT);
+}
+
+#[tokio::test]
+async fn main() {
+    init_static().await.unwrap();
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    assert_eq!(*ADDR, "localhost:8080");
+    assert!(InitStatic::is_set(&PORT));
+}
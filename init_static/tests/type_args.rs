@@ -0,0 +1,30 @@
+use init_static::init_static;
+
+const LEN: usize = 2;
+
+struct Window<const N: usize>;
+
+impl<const N: usize> Window<N> {
+    fn sum(values: &[u32]) -> u32 {
+        values.iter().take(N).sum()
+    }
+}
+
+// An ALL_CAPS braced struct used as a type argument is a type, not a dependency.
+#[allow(clippy::upper_case_acronyms)]
+struct UUID {
+    v: u64,
+}
+
+init_static! {
+    static VALUES: Vec<u32> = Vec::<u32>::from([1, 2, 3]);
+    static SUM: u32 = Window::<{ LEN }>::sum(&VALUES);
+    static IDS: Vec<UUID> = Vec::<UUID>::from([UUID { v: u64::from(*SUM) }]);
+}
+
+#[tokio::test]
+async fn main() {
+    init_static().await.unwrap();
+    assert_eq!(*SUM, 3);
+    assert_eq!(IDS.iter().map(|id| id.v).collect::<Vec<_>>(), [3]);
+}
@@ -0,0 +1,27 @@
+use init_static::{InitStatic, Symbol, init_static};
+
+init_static! {
+    static LIST: Vec<u32> = Vec::from(["1".parse()?]);
+}
+
+#[tokio::test]
+async fn main() {
+    let symbol = InitStatic::symbol(&LIST);
+    assert_eq!(symbol.type_name(), Some(std::any::type_name::<Vec<u32>>()));
+    assert!(format!("{symbol:#}").contains(&format!("::LIST: {} (at ", std::any::type_name::<Vec<u32>>())));
+    assert!(format!("{symbol}").contains("::LIST (at "));
+
+    // A symbol created without a type, e.g. with a struct literal, has no type name.
+    let symbol = Symbol {
+        file: file!(),
+        line: line!(),
+        column: column!(),
+        module: module_path!(),
+        ident: "MANUAL",
+    };
+    assert_eq!(symbol.type_name(), None);
+    assert_eq!(format!("{symbol:#}"), format!("{symbol}"));
+
+    init_static().await.unwrap();
+    assert_eq!(*LIST, [1]);
+}
@@ -0,0 +1,20 @@
+use std::panic::catch_unwind;
+
+use init_static::{InitStatic, set_uninit_hook};
+
+static CONFIG: InitStatic<u32> = InitStatic!(CONFIG);
+
+fn panic_message(f: impl FnOnce() + std::panic::UnwindSafe) -> String {
+    let payload = catch_unwind(f).unwrap_err();
+    payload.downcast_ref::<String>().cloned().unwrap()
+}
+
+#[test]
+fn main() {
+    let message = panic_message(|| println!("{}", *CONFIG));
+    assert!(message.starts_with("access to uninitialized init_static: "));
+    assert!(message.contains("hint: call `init_static()`"));
+
+    set_uninit_hook(|symbol| format!("{} is not loaded", symbol.ident));
+    assert_eq!(panic_message(|| println!("{}", *CONFIG)), "CONFIG is not loaded");
+}
@@ -0,0 +1,26 @@
+use std::sync::LazyLock;
+
+use init_static::{init_static, unmanaged_dependencies};
+
+static PORT: u16 = 8080;
+static HOST: LazyLock<String> = LazyLock::new(|| "localhost".to_owned());
+
+init_static! {
+    static ADDR: String = format!("{}:{}", *HOST, PORT);
+    static URL: String = format!("http://{}", *ADDR);
+    #[ignore_deps(PORT)]
+    static BACKUP: u16 = PORT + 1;
+}
+
+#[test]
+fn main() {
+    let unmanaged = unmanaged_dependencies()
+        .into_iter()
+        .map(|(symbol, path)| (symbol.ident, path))
+        .collect::<Vec<_>>();
+    if cfg!(debug_assertions) {
+        assert_eq!(unmanaged, [("ADDR", "PORT")]);
+    } else {
+        assert!(unmanaged.is_empty());
+    }
+}
@@ -0,0 +1,21 @@
+#![feature(used_with_arg)]
+
+use init_static::{InitStatic, init_static, init_thread_local};
+
+init_static! {
+    static BASE: u32 = "1".parse()?;
+    static VALUE: u32 = *BASE + 1;
+}
+
+init_thread_local! {
+    static LOCAL: u32 = *VALUE + 1;
+}
+
+#[tokio::test]
+async fn main() {
+    init_static().await.unwrap();
+    assert_eq!(*VALUE, 2);
+    init_thread_local().unwrap();
+    assert_eq!(LOCAL.with(|local| **local), 3);
+    assert!(InitStatic::symbol(&VALUE).type_name().is_some());
+}
@@ -0,0 +1,16 @@
+use init_static::{InitStatic, init_static, validate_graph};
+
+fn must_not_run() -> u32 {
+    panic!("initializers must not run")
+}
+
+init_static! {
+    static FOO: u32 = *BAR + 1;
+    static BAR: u32 = must_not_run();
+}
+
+#[test]
+fn main() {
+    validate_graph().unwrap();
+    assert_eq!(InitStatic::get(&FOO), None);
+}
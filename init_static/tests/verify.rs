@@ -0,0 +1,25 @@
+use init_static::{InitError, InitStatic, init_static, verify_all_initialized};
+
+init_static! {
+    static REGISTERED: u32 = 1;
+}
+
+static MANUAL: InitStatic<u32> = InitStatic!(MANUAL);
+
+#[tokio::test]
+async fn main() {
+    let lazy = InitStatic!(LAZY);
+
+    init_static().await.unwrap();
+    let error = verify_all_initialized().unwrap_err();
+    let Some(InitError::Uninitialized { symbols }) = error.downcast_ref::<InitError>() else {
+        panic!("unexpected error: {error:?}");
+    };
+    let names = symbols.iter().map(|symbol| symbol.ident).collect::<Vec<_>>();
+    assert_eq!(names, ["MANUAL", "LAZY"]);
+
+    InitStatic::init(&MANUAL, 2);
+    assert_eq!(*InitStatic::get_or_init(&lazy, || 3), 3);
+    verify_all_initialized().unwrap();
+    assert_eq!(*REGISTERED + *MANUAL + *lazy, 6);
+}
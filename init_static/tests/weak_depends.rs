@@ -0,0 +1,20 @@
+use init_static::{InitStatic, MissingDepPolicy, dependency_graph, init_static, set_missing_dep_policy};
+
+static MANUAL: InitStatic<u32> = InitStatic!(MANUAL);
+
+init_static! {
+    static CACHE: u32 = "41".parse()?;
+    #[weak_depends(CACHE, MANUAL)]
+    static VALUE: u32 = *CACHE + InitStatic::get(&MANUAL).copied().unwrap_or(1);
+}
+
+#[tokio::test]
+async fn main() {
+    set_missing_dep_policy(MissingDepPolicy::Error);
+    let graph = dependency_graph();
+    let (_, deps) = graph.iter().find(|(symbol, _)| symbol.ident == "VALUE").unwrap();
+    assert_eq!(deps.iter().map(|symbol| symbol.ident).collect::<Vec<_>>(), ["CACHE"]);
+
+    init_static().await.unwrap();
+    assert_eq!(*VALUE, 42);
+}
@@ -0,0 +1,10 @@
+use init_static::init_static;
+
+init_static! {
+    {
+        static A: u32 = "1".parse()?;
+        let b = *A + 1;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use init_static::init_static;
+
+init_static! {
+    static A: u32 = "1".parse()?
+    static B: u32 = *A + 1;
+}
+
+fn main() {}
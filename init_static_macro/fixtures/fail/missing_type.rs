@@ -0,0 +1,7 @@
+use init_static::init_static;
+
+init_static! {
+    static A = "1".parse()?;
+}
+
+fn main() {}
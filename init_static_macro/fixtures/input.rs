@@ -1 +1,27 @@
+mod any_case;
+mod attrs;
 mod basic;
+mod cfg;
+mod const_init;
+mod context;
+mod control_flow;
+mod deinit;
+mod dep;
+mod depends;
+mod deps_fn;
+mod drop_on_deinit;
+mod expose_init;
+mod generics;
+mod ignore_deps;
+mod init_allow;
+mod init_async;
+mod lazy;
+mod macros;
+mod receiver;
+mod register;
+mod spawn;
+mod syntax;
+mod thread_local;
+mod tuple;
+mod type_deps;
+mod weak_depends;
@@ -0,0 +1,21 @@
+#![allow(non_upper_case_globals)]
+
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+fn double(x: u32) -> u32 {
+    x * 2
+}
+
+#[rustfmt::skip]
+init_static! {
+    #![any_case_deps]
+    static base: u32 = 42;
+    static derived: u32 = [*base].map(double).into_iter().sum();
+}
+
+#[rustfmt::skip]
+init_static! {
+    #[any_case_deps]
+    static other: u32 = double(*derived);
+}
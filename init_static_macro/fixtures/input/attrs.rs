@@ -0,0 +1,15 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+#[allow(dead_code)]
+fn close(_: &u32) {}
+
+#[rustfmt::skip]
+init_static! {
+    /// Always available.
+    pub static V1: u32 = 42;
+    /// Only available outside of tests.
+    #[cfg(not(test))]
+    #[deinit(close)]
+    static V2: u32 = *V1 + 1;
+}
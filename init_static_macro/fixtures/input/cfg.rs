@@ -0,0 +1,20 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+#[rustfmt::skip]
+init_static! {
+    #[cfg(any())]
+    static METRICS: u32 = 1;
+    static REPORT: u32 = {
+        #[cfg(any())]
+        let metrics = *METRICS;
+        #[cfg(not(any()))]
+        let metrics = 0;
+        metrics + 1
+    };
+    static TOTAL: u32 = match *REPORT {
+        #[cfg(any())]
+        0 => *METRICS,
+        n => n + *REPORT,
+    };
+}
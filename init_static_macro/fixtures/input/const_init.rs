@@ -0,0 +1,11 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+#[rustfmt::skip]
+init_static! {
+    static PORT: u16 = 8080;
+    static OFFSET: (i32, [f64; 2]) = (-1, [0.5, 1.5]);
+    static NAME: &str = "localhost";
+    static ADDR: String = format!("{}:{}", *NAME, *PORT);
+    static mut COUNTER: u32 = 0;
+}
@@ -0,0 +1,16 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+pub struct Config {
+    pub host: &'static str,
+    pub port: u16,
+}
+
+#[rustfmt::skip]
+init_static! {
+    #[any_case_deps]
+    #[context(ctx: Config)]
+    static PORT: u16 = ctx.port;
+    #[context(config: Config)]
+    static ADDR: String = async { format!("{}:{}", config.host, *PORT) }.await;
+}
@@ -0,0 +1,32 @@
+#![allow(non_snake_case)]
+
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+#[allow(dead_code)]
+#[derive(Clone, Copy)]
+struct Config {
+    a: u32,
+    b: u32,
+}
+
+#[rustfmt::skip]
+init_static! {
+    static V0: u32 = 0;
+    static V1: Option<u32> = Some(42);
+    static V2: u32 = match *V1 {
+        Some(N) if N > *V0 => N,
+        _ => *V0,
+    };
+    static V3: u32 = if let Some(N) = *V1 { N } else { *V0 };
+    static V4: u32 = {
+        let mut list = vec![*V2, *V3];
+        let mut sum = 0;
+        while let Some(N) = list.pop() {
+            sum += N;
+        }
+        sum
+    };
+    static BASE: Config = Config { a: 0, b: 0 };
+    static V5: Config = Config { a: *V4, ..*BASE };
+}
@@ -0,0 +1,14 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+fn close(_: &u32) {}
+
+async fn close_async(_: &u32) {}
+
+#[rustfmt::skip]
+init_static! {
+    #[deinit(close)]
+    static V1: u32 = 42;
+    #[deinit(async close_async)]
+    static V2: u32 = *V1;
+}
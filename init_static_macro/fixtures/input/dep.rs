@@ -0,0 +1,14 @@
+#[allow(unused_imports)]
+use init_static_macro::{init_static, init_thread_local};
+
+#[rustfmt::skip]
+init_static! {
+    static NAMES: Vec<&'static str> = vec!["foo", "bar"];
+    static COUNT: usize = init_static::dep!(NAMES).len();
+    static FIRST: &'static str = async { init_static::dep!(NAMES)[0] }.await;
+}
+
+#[rustfmt::skip]
+init_thread_local! {
+    static LOCAL_COUNT: usize = init_static::dep!(COUNT) + 1;
+}
@@ -0,0 +1,15 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+fn helper() -> u32 {
+    *V1 + 1
+}
+
+#[rustfmt::skip]
+init_static! {
+    static V1: u32 = 42;
+    #[depends(V1)]
+    static V2: u32 = helper();
+    #[depends(V1, self::V2)]
+    static V3: u32 = *V1 + helper();
+}
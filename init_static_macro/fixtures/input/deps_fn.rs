@@ -0,0 +1,13 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+fn v2_deps() -> Vec<Option<&'static init_static::Symbol>> {
+    vec![]
+}
+
+#[rustfmt::skip]
+init_static! {
+    static V1: u32 = 42;
+    #[deps_fn(v2_deps)]
+    static V2: u32 = *V1;
+}
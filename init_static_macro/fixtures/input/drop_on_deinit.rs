@@ -0,0 +1,19 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+fn flush(_: &Vec<u8>) {}
+
+#[rustfmt::skip]
+init_static! {
+    #[drop_on_deinit]
+    static mut V1: Vec<u8> = Vec::new();
+    #[deinit(flush)]
+    #[drop_on_deinit]
+    static mut V2: Vec<u8> = Vec::new();
+}
+
+#[rustfmt::skip]
+init_static! {
+    #[depends(V1, V2)]
+    static V3: u32 = 42;
+}
@@ -0,0 +1,10 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+#[rustfmt::skip]
+init_static! {
+    #[expose_init]
+    pub static V1: u32 = "1".parse()?;
+    #[expose_init]
+    static V2: u32 = async { *V1 + 1 }.await;
+}
@@ -0,0 +1,25 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+const LEN: usize = 2;
+
+struct Window<const N: usize>;
+
+impl<const N: usize> Window<N> {
+    fn sum(values: &[u32]) -> u32 {
+        values.iter().take(N).sum()
+    }
+}
+
+#[allow(clippy::upper_case_acronyms)]
+struct UUID {
+    _v: u64,
+}
+
+#[rustfmt::skip]
+init_static! {
+    static V1: Vec<u32> = Vec::<u32>::from([1, 2, 3]);
+    static V2: u32 = Window::<{ LEN }>::sum(&V1);
+    static V3: u32 = Window::<{ LEN + 1 }>::sum(&V1) + *V2;
+    static IDS: Vec<UUID> = Vec::<UUID>::with_capacity(*V2 as usize);
+}
@@ -0,0 +1,14 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+const MAX_RETRIES: u32 = 3;
+
+#[rustfmt::skip]
+init_static! {
+    static V1: u32 = 42;
+    #[ignore_deps(MAX_RETRIES, u32::BITS)]
+    static V2: u32 = *V1 + MAX_RETRIES * u32::BITS;
+    #[no_deps]
+    #[depends(V2)]
+    static V3: u32 = *V1 + MAX_RETRIES;
+}
@@ -0,0 +1,10 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+#[rustfmt::skip]
+init_static! {
+    #[init_allow(clippy::approx_constant)]
+    static PI: f64 = 3.14;
+    #[init_allow(clippy::identity_op, clippy::erasing_op)]
+    static ZERO: u32 = (*PI as u32) * 0 + 0;
+}
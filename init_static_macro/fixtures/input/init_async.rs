@@ -0,0 +1,15 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+#[rustfmt::skip]
+macro_rules! fetch {
+    ($future:expr) => {
+        $future.await
+    };
+}
+
+#[rustfmt::skip]
+init_static! {
+    #[init_async]
+    static V1: u32 = fetch!(async { 42 });
+}
@@ -0,0 +1,12 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+use std::sync::LazyLock;
+
+static BASE: LazyLock<u32> = LazyLock::new(|| 42);
+
+#[rustfmt::skip]
+init_static! {
+    static V1: u32 = *BASE + 1;
+    static V2: u32 = async { *BASE + *V1 }.await;
+}
@@ -0,0 +1,16 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+macro_rules! add {
+    ($a:expr => $b:expr) => {
+        $a + $b
+    };
+}
+
+#[rustfmt::skip]
+init_static! {
+    static V1: u32 = 42;
+    static V2: String = format!("{}", *V1);
+    static V3: Vec<u32> = vec![*V1, u32::MAX];
+    static V4: u32 = add!(*V1 => V3.len() as u32);
+}
@@ -0,0 +1,20 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+struct Config {
+    db: Db,
+}
+
+struct Db {
+    url: String,
+}
+
+#[rustfmt::skip]
+init_static! {
+    static CONFIG: Config = Config { db: Db { url: "postgres://localhost".parse()? } };
+    static NAMES: Vec<String> = "foo,bar".split(',').map(String::from).collect();
+    static URL: String = CONFIG.db.url.clone();
+    static COUNT: usize = NAMES.iter().count();
+    static FIRST: String = NAMES[*COUNT - 2].to_uppercase();
+    static LEN: usize = (*URL).len();
+}
@@ -0,0 +1,11 @@
+#[allow(unused_imports)]
+use init_static_macro::register;
+
+#[rustfmt::skip]
+#[register]
+static BASE: u32 = "42".parse()?;
+
+#[rustfmt::skip]
+#[register]
+#[depends(BASE)]
+static DERIVED: u32 = async { BASE.checked_add(1).unwrap() }.await;
@@ -0,0 +1,14 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+async fn double(value: u32) -> u32 {
+    value * 2
+}
+
+#[rustfmt::skip]
+init_static! {
+    static V1: u32 = async { 42 }.await;
+    static V2: tokio::task::JoinHandle<u32> = tokio::spawn(async { double(*V1).await + 1 });
+    static V3: Box<dyn Fn() -> tokio::task::JoinHandle<u32> + Send + Sync> =
+        Box::new(|| tokio::spawn(async { double(*V1).await + 2 }));
+}
@@ -0,0 +1,12 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+#[rustfmt::skip]
+init_static! {
+    static V1: u32 = "1".parse()?,
+    {
+        static V2: u32 = *V1 + 1;
+        static V3: u32 = *V2 + 1
+    }
+    static V4: u32 = *V3 + 1
+}
@@ -0,0 +1,9 @@
+#[allow(unused_imports)]
+use init_static_macro::init_thread_local;
+
+#[rustfmt::skip]
+init_thread_local! {
+    /// The capacity of the buffer.
+    static CAPACITY: usize = "1024".parse()?;
+    pub static BUFFER: Vec<u8> = Vec::with_capacity(CAPACITY.with(|capacity| **capacity));
+}
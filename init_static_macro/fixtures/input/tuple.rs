@@ -0,0 +1,12 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+#[rustfmt::skip]
+init_static! {
+    static (HOST, PORT): (String, u16) = ("localhost".to_string(), 8080);
+    static (ADDR, LEN): (String, usize) = async {
+        let addr = format!("{}:{}", *HOST, *PORT);
+        let len = addr.len();
+        (addr, len)
+    }.await;
+}
@@ -0,0 +1,11 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+pub mod sizes {
+    pub const BUF_LEN: usize = 16;
+}
+
+#[rustfmt::skip]
+init_static! {
+    static BUF: [u8; sizes::BUF_LEN] = Default::default();
+}
@@ -0,0 +1,11 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+
+#[rustfmt::skip]
+init_static! {
+    static CACHE: u32 = "42".parse()?;
+    #[weak_depends(CACHE)]
+    static V1: u32 = "1".parse()?;
+    #[weak_depends(CACHE, V1)]
+    static V2: u32 = *V1 + 1;
+}
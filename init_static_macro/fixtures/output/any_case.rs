@@ -0,0 +1,161 @@
+#![allow(non_upper_case_globals)]
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+fn double(x: u32) -> u32 {
+    x * 2
+}
+#[rustfmt::skip]
+#[allow(clippy::type_complexity, dead_code)]
+static base: ::init_static::InitStatic<u32> = ::init_static::InitStatic::with_value(
+    {
+        #[::init_static::__private::distributed_slice(
+            ::init_static::__private::CONST_SYMBOLS
+        )]
+        #[linkme(crate = ::init_static::__private::linkme)]
+        static SYMBOL: &::init_static::Symbol = ::init_static::Symbol!(base : u32);
+        SYMBOL
+    },
+    42,
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static derived: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(
+    derived : u32
+);
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_derived: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_derived() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&base).__force();
+                (&double).__force();
+            }
+            ::init_static::InitStatic::init(
+                &derived,
+                [*base].map(double).into_iter().sum(),
+            );
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_derived: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[
+                || unsafe { (&base).__get_symbol() },
+                || unsafe { (&double).__get_symbol() },
+            ]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_derived() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [
+                    (&base).__is_unmanaged().then_some("base"),
+                    (&double).__is_unmanaged().then_some("double"),
+                ]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_derived() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&derived),
+            init: ::init_static::__private::InitFn::Sync(&INIT_derived),
+            deps: ::init_static::__private::Deps::Static(DEPS_derived),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_derived,
+        }
+    };
+};
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static other: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(other : u32);
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_other: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_other() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&derived).__force();
+            }
+            ::init_static::InitStatic::init(&other, double(*derived));
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_other: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&derived).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_other() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [(&derived).__is_unmanaged().then_some("derived")]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_other() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&other),
+            init: ::init_static::__private::InitFn::Sync(&INIT_other),
+            deps: ::init_static::__private::Deps::Static(DEPS_other),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_other,
+        }
+    };
+};
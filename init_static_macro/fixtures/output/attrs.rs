@@ -0,0 +1,96 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+#[allow(dead_code)]
+fn close(_: &u32) {}
+#[rustfmt::skip]
+/// Always available.
+#[allow(clippy::type_complexity, dead_code)]
+pub static V1: ::init_static::InitStatic<u32> = ::init_static::InitStatic::with_value(
+    {
+        #[::init_static::__private::distributed_slice(
+            ::init_static::__private::CONST_SYMBOLS
+        )]
+        #[linkme(crate = ::init_static::__private::linkme)]
+        static SYMBOL: &::init_static::Symbol = ::init_static::Symbol!(V1 : u32);
+        SYMBOL
+    },
+    42,
+);
+#[rustfmt::skip]
+/// Only available outside of tests.
+#[cfg(not(test))]
+#[allow(clippy::type_complexity)]
+static V2: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V2 : u32);
+#[rustfmt::skip]
+const _: () = {
+    #[cfg(not(test))]
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_V2: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_V2() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&V1).__force();
+            }
+            ::init_static::InitStatic::init(&V2, *V1 + 1);
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_V2: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&V1).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_V2() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [(&V1).__is_unmanaged().then_some("V1")].into_iter().flatten().collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_V2() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&V2),
+            init: ::init_static::__private::InitFn::Sync(&INIT_V2),
+            deps: ::init_static::__private::Deps::Static(DEPS_V2),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_V2,
+        }
+    };
+    #[cfg(not(test))]
+    #[::init_static::__private::distributed_slice(::init_static::__private::DEINIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static DEINIT_V2: ::init_static::__private::Deinit = {
+        #[allow(non_snake_case, clippy::explicit_auto_deref)]
+        fn DEINIT_V2() {
+            (close)(&*V2);
+        }
+        ::init_static::__private::Deinit {
+            symbol: ::init_static::InitStatic::symbol(&V2),
+            deinit: ::init_static::__private::DeinitFn::Sync(DEINIT_V2),
+        }
+    };
+};
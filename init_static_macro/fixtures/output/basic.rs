@@ -2,93 +2,159 @@
 use init_static_macro::init_static;
 #[rustfmt::skip]
 #[allow(clippy::type_complexity)]
-static V1: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V1);
+static V1: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V1 : u32);
 #[rustfmt::skip]
 #[allow(clippy::type_complexity)]
-static V2: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V2);
+static V2: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V2 : u32);
 #[rustfmt::skip]
 #[allow(clippy::type_complexity)]
-static V3: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V3);
+static V3: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V3 : u32);
 #[rustfmt::skip]
 #[allow(clippy::type_complexity)]
-static V4: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V4);
+static V4: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V4 : u32);
 #[rustfmt::skip]
 const _: () = {
-    #[::init_static::__private::linkme::distributed_slice(
-        ::init_static::__private::INIT
-    )]
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
     #[linkme(crate = ::init_static::__private::linkme)]
     static INIT_V1: ::init_static::__private::Init = {
         #[allow(non_snake_case)]
-        fn INIT_V1() -> ::init_static::__private::anyhow::Result<()> {
+        fn INIT_V1() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&N1).__force();
+            }
             ::init_static::InitStatic::init(&V1, N1);
             Ok(())
         }
-        #[allow(non_snake_case, clippy::needless_borrow)]
-        fn DEPS_V1() -> ::std::vec::Vec<
-            ::std::option::Option<&'static ::init_static::Symbol>,
-        > {
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_V1: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&N1).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_V1() -> ::std::vec::Vec<&'static str> {
             use ::init_static::__private::MaybeInitStatic;
-            ::std::vec![(& N1).__get_symbol()]
+            unsafe {
+                [(&N1).__is_unmanaged().then_some("N1")].into_iter().flatten().collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_V1() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
         }
         ::init_static::__private::Init {
             symbol: ::init_static::InitStatic::symbol(&V1),
-            init: ::init_static::__private::InitFn::Sync(INIT_V1),
-            deps: DEPS_V1,
+            init: ::init_static::__private::InitFn::Sync(&INIT_V1),
+            deps: ::init_static::__private::Deps::Static(DEPS_V1),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_V1,
         }
     };
-    #[::init_static::__private::linkme::distributed_slice(
-        ::init_static::__private::INIT
-    )]
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
     #[linkme(crate = ::init_static::__private::linkme)]
     static INIT_V2: ::init_static::__private::Init = {
         #[allow(non_snake_case)]
-        fn INIT_V2() -> ::init_static::__private::anyhow::Result<()> {
+        fn INIT_V2() -> ::init_static::__private::Result<()> {
             ::init_static::InitStatic::init(&V2, "42".parse()?);
             Ok(())
         }
         ::init_static::__private::Init {
             symbol: ::init_static::InitStatic::symbol(&V2),
-            init: ::init_static::__private::InitFn::Sync(INIT_V2),
-            deps: ::std::vec::Vec::new,
+            init: ::init_static::__private::InitFn::Sync(&INIT_V2),
+            deps: ::init_static::__private::Deps::Static(&[]),
+            weak_deps: &[],
+            unmanaged: &::std::vec::Vec::new,
         }
     };
-    #[::init_static::__private::linkme::distributed_slice(
-        ::init_static::__private::INIT
-    )]
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
     #[linkme(crate = ::init_static::__private::linkme)]
     static INIT_V3: ::init_static::__private::Init = {
         #[allow(non_snake_case)]
         fn INIT_V3() -> ::init_static::__private::BoxFuture<
-            ::init_static::__private::anyhow::Result<()>,
+            ::init_static::__private::Result<()>,
         > {
+            ::init_static::__private::require_async!(V3);
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&N1).__force();
+            }
             Box::pin(async {
                 ::init_static::InitStatic::init(&V3, async { N1 }.await);
                 Ok(())
             })
         }
-        #[allow(non_snake_case, clippy::needless_borrow)]
-        fn DEPS_V3() -> ::std::vec::Vec<
-            ::std::option::Option<&'static ::init_static::Symbol>,
-        > {
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_V3: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&N1).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_V3() -> ::std::vec::Vec<&'static str> {
             use ::init_static::__private::MaybeInitStatic;
-            ::std::vec![(& N1).__get_symbol()]
+            unsafe {
+                [(&N1).__is_unmanaged().then_some("N1")].into_iter().flatten().collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_V3() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
         }
         ::init_static::__private::Init {
             symbol: ::init_static::InitStatic::symbol(&V3),
-            init: ::init_static::__private::InitFn::Async(INIT_V3),
-            deps: DEPS_V3,
+            init: ::init_static::__private::InitFn::Async(&INIT_V3),
+            deps: ::init_static::__private::Deps::Static(DEPS_V3),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_V3,
         }
     };
-    #[::init_static::__private::linkme::distributed_slice(
-        ::init_static::__private::INIT
-    )]
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
     #[linkme(crate = ::init_static::__private::linkme)]
     static INIT_V4: ::init_static::__private::Init = {
         #[allow(non_snake_case)]
         fn INIT_V4() -> ::init_static::__private::BoxFuture<
-            ::init_static::__private::anyhow::Result<()>,
+            ::init_static::__private::Result<()>,
         > {
+            ::init_static::__private::require_async!(V4);
             Box::pin(async {
                 ::init_static::InitStatic::init(&V4, async { "42".parse() }.await?);
                 Ok(())
@@ -96,8 +162,10 @@ const _: () = {
         }
         ::init_static::__private::Init {
             symbol: ::init_static::InitStatic::symbol(&V4),
-            init: ::init_static::__private::InitFn::Async(INIT_V4),
-            deps: ::std::vec::Vec::new,
+            init: ::init_static::__private::InitFn::Async(&INIT_V4),
+            deps: ::init_static::__private::Deps::Static(&[]),
+            weak_deps: &[],
+            unmanaged: &::std::vec::Vec::new,
         }
     };
 };
@@ -105,16 +173,25 @@ const _: () = {
 const N1: u32 = 42;
 #[rustfmt::skip]
 #[allow(clippy::type_complexity)]
-static V5: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V5);
+static V5: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V5 : u32);
 #[rustfmt::skip]
 const _: () = {
-    #[::init_static::__private::linkme::distributed_slice(
-        ::init_static::__private::INIT
-    )]
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
     #[linkme(crate = ::init_static::__private::linkme)]
     static INIT_V5: ::init_static::__private::Init = {
         #[allow(non_snake_case)]
-        fn INIT_V5() -> ::init_static::__private::anyhow::Result<()> {
+        fn INIT_V5() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&N1).__force();
+                (&V1).__force();
+            }
             ::init_static::InitStatic::init(
                 &V5,
                 {
@@ -126,17 +203,48 @@ const _: () = {
             );
             Ok(())
         }
-        #[allow(non_snake_case, clippy::needless_borrow)]
-        fn DEPS_V5() -> ::std::vec::Vec<
-            ::std::option::Option<&'static ::init_static::Symbol>,
-        > {
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_V5: &[::init_static::__private::DepFn] = {
             use ::init_static::__private::MaybeInitStatic;
-            ::std::vec![(& N1).__get_symbol(), (& V1).__get_symbol()]
+            &[|| unsafe { (&N1).__get_symbol() }, || unsafe { (&V1).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_V5() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [
+                    (&N1).__is_unmanaged().then_some("N1"),
+                    (&V1).__is_unmanaged().then_some("V1"),
+                ]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_V5() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
         }
         ::init_static::__private::Init {
             symbol: ::init_static::InitStatic::symbol(&V5),
-            init: ::init_static::__private::InitFn::Sync(INIT_V5),
-            deps: DEPS_V5,
+            init: ::init_static::__private::InitFn::Sync(&INIT_V5),
+            deps: ::init_static::__private::Deps::Static(DEPS_V5),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_V5,
         }
     };
 };
@@ -0,0 +1,198 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+#[rustfmt::skip]
+#[cfg(any())]
+#[allow(clippy::type_complexity, dead_code)]
+static METRICS: ::init_static::InitStatic<u32> = ::init_static::InitStatic::with_value(
+    {
+        #[::init_static::__private::distributed_slice(
+            ::init_static::__private::CONST_SYMBOLS
+        )]
+        #[linkme(crate = ::init_static::__private::linkme)]
+        static SYMBOL: &::init_static::Symbol = ::init_static::Symbol!(METRICS : u32);
+        SYMBOL
+    },
+    1,
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static REPORT: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(REPORT : u32);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static TOTAL: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(TOTAL : u32);
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_REPORT: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_REPORT() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                #[cfg(any())] (&METRICS).__force();
+            }
+            ::init_static::InitStatic::init(
+                &REPORT,
+                {
+                    #[cfg(any())]
+                    let metrics = *METRICS;
+                    #[cfg(not(any()))]
+                    let metrics = 0;
+                    metrics + 1
+                },
+            );
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_REPORT: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[
+                || unsafe {
+                    {
+                        #[cfg(any())]
+                        let dep = (&METRICS).__get_symbol();
+                        #[cfg(not(any()))]
+                        let dep = ::std::option::Option::None;
+                        dep
+                    }
+                },
+            ]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_REPORT() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [
+                    {
+                        #[cfg(any())]
+                        let dep = (&METRICS).__is_unmanaged().then_some("METRICS");
+                        #[cfg(not(any()))]
+                        let dep = ::std::option::Option::None;
+                        dep
+                    },
+                ]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_REPORT() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&REPORT),
+            init: ::init_static::__private::InitFn::Sync(&INIT_REPORT),
+            deps: ::init_static::__private::Deps::Static(DEPS_REPORT),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_REPORT,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_TOTAL: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_TOTAL() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                #[cfg(any())] (&METRICS).__force();
+                (&REPORT).__force();
+            }
+            ::init_static::InitStatic::init(
+                &TOTAL,
+                match *REPORT {
+                    #[cfg(any())]
+                    0 => *METRICS,
+                    n => n + *REPORT,
+                },
+            );
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_TOTAL: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[
+                || unsafe {
+                    {
+                        #[cfg(any())]
+                        let dep = (&METRICS).__get_symbol();
+                        #[cfg(not(any()))]
+                        let dep = ::std::option::Option::None;
+                        dep
+                    }
+                },
+                || unsafe { (&REPORT).__get_symbol() },
+            ]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_TOTAL() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [
+                    {
+                        #[cfg(any())]
+                        let dep = (&METRICS).__is_unmanaged().then_some("METRICS");
+                        #[cfg(not(any()))]
+                        let dep = ::std::option::Option::None;
+                        dep
+                    },
+                    (&REPORT).__is_unmanaged().then_some("REPORT"),
+                ]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_TOTAL() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&TOTAL),
+            init: ::init_static::__private::InitFn::Sync(&INIT_TOTAL),
+            deps: ::init_static::__private::Deps::Static(DEPS_TOTAL),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_TOTAL,
+        }
+    };
+};
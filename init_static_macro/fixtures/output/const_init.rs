@@ -0,0 +1,140 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+#[rustfmt::skip]
+#[allow(clippy::type_complexity, dead_code)]
+static PORT: ::init_static::InitStatic<u16> = ::init_static::InitStatic::with_value(
+    {
+        #[::init_static::__private::distributed_slice(
+            ::init_static::__private::CONST_SYMBOLS
+        )]
+        #[linkme(crate = ::init_static::__private::linkme)]
+        static SYMBOL: &::init_static::Symbol = ::init_static::Symbol!(PORT : u16);
+        SYMBOL
+    },
+    8080,
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity, dead_code)]
+static OFFSET: ::init_static::InitStatic<(i32, [f64; 2])> = ::init_static::InitStatic::with_value(
+    {
+        #[::init_static::__private::distributed_slice(
+            ::init_static::__private::CONST_SYMBOLS
+        )]
+        #[linkme(crate = ::init_static::__private::linkme)]
+        static SYMBOL: &::init_static::Symbol = ::init_static::Symbol!(
+            OFFSET : (i32, [f64; 2])
+        );
+        SYMBOL
+    },
+    (-1, [0.5, 1.5]),
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity, dead_code)]
+static NAME: ::init_static::InitStatic<&str> = ::init_static::InitStatic::with_value(
+    {
+        #[::init_static::__private::distributed_slice(
+            ::init_static::__private::CONST_SYMBOLS
+        )]
+        #[linkme(crate = ::init_static::__private::linkme)]
+        static SYMBOL: &::init_static::Symbol = ::init_static::Symbol!(NAME : & str);
+        SYMBOL
+    },
+    "localhost",
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static ADDR: ::init_static::InitStatic<String> = ::init_static::InitStatic!(
+    ADDR : String
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static mut COUNTER: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(
+    COUNTER : u32
+);
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_ADDR: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_ADDR() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&NAME).__force();
+                (&PORT).__force();
+            }
+            ::init_static::InitStatic::init(&ADDR, format!("{}:{}", * NAME, * PORT));
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_ADDR: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&NAME).__get_symbol() }, || unsafe { (&PORT).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_ADDR() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [
+                    (&NAME).__is_unmanaged().then_some("NAME"),
+                    (&PORT).__is_unmanaged().then_some("PORT"),
+                ]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_ADDR() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&ADDR),
+            init: ::init_static::__private::InitFn::Sync(&INIT_ADDR),
+            deps: ::init_static::__private::Deps::Static(DEPS_ADDR),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_ADDR,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_COUNTER: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_COUNTER() -> ::init_static::__private::Result<()> {
+            ::init_static::InitStatic::init(
+                unsafe { &*::std::ptr::addr_of!(COUNTER) },
+                0,
+            );
+            Ok(())
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(unsafe {
+                &*::std::ptr::addr_of!(COUNTER)
+            }),
+            init: ::init_static::__private::InitFn::Sync(&INIT_COUNTER),
+            deps: ::init_static::__private::Deps::Static(&[]),
+            weak_deps: &[],
+            unmanaged: &::std::vec::Vec::new,
+        }
+    };
+};
@@ -0,0 +1,108 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+pub struct Config {
+    pub host: &'static str,
+    pub port: u16,
+}
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static PORT: ::init_static::InitStatic<u16> = ::init_static::InitStatic!(PORT : u16);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static ADDR: ::init_static::InitStatic<String> = ::init_static::InitStatic!(
+    ADDR : String
+);
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_PORT: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_PORT() -> ::init_static::__private::Result<()> {
+            let ctx = ::init_static::__private::context::<
+                Config,
+            >(::init_static::InitStatic::symbol(&PORT))?;
+            let ctx: &Config = &ctx;
+            ::init_static::InitStatic::init(&PORT, ctx.port);
+            Ok(())
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&PORT),
+            init: ::init_static::__private::InitFn::Sync(&INIT_PORT),
+            deps: ::init_static::__private::Deps::Static(&[]),
+            weak_deps: &[],
+            unmanaged: &::std::vec::Vec::new,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_ADDR: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_ADDR() -> ::init_static::__private::BoxFuture<
+            ::init_static::__private::Result<()>,
+        > {
+            ::init_static::__private::require_async!(ADDR);
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&PORT).__force();
+            }
+            Box::pin(async {
+                let config = ::init_static::__private::context::<
+                    Config,
+                >(::init_static::InitStatic::symbol(&ADDR))?;
+                let config: &Config = &config;
+                ::init_static::InitStatic::init(
+                    &ADDR,
+                    async { format!("{}:{}", config.host, * PORT) }.await,
+                );
+                Ok(())
+            })
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_ADDR: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&PORT).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_ADDR() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [(&PORT).__is_unmanaged().then_some("PORT")]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_ADDR() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&ADDR),
+            init: ::init_static::__private::InitFn::Async(&INIT_ADDR),
+            deps: ::init_static::__private::Deps::Static(DEPS_ADDR),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_ADDR,
+        }
+    };
+};
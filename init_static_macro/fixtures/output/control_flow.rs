@@ -0,0 +1,350 @@
+#![allow(non_snake_case)]
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+#[allow(dead_code)]
+#[derive(Clone, Copy)]
+struct Config {
+    a: u32,
+    b: u32,
+}
+#[rustfmt::skip]
+#[allow(clippy::type_complexity, dead_code)]
+static V0: ::init_static::InitStatic<u32> = ::init_static::InitStatic::with_value(
+    {
+        #[::init_static::__private::distributed_slice(
+            ::init_static::__private::CONST_SYMBOLS
+        )]
+        #[linkme(crate = ::init_static::__private::linkme)]
+        static SYMBOL: &::init_static::Symbol = ::init_static::Symbol!(V0 : u32);
+        SYMBOL
+    },
+    0,
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static V1: ::init_static::InitStatic<Option<u32>> = ::init_static::InitStatic!(
+    V1 : Option < u32 >
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static V2: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V2 : u32);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static V3: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V3 : u32);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static V4: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V4 : u32);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static BASE: ::init_static::InitStatic<Config> = ::init_static::InitStatic!(
+    BASE : Config
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static V5: ::init_static::InitStatic<Config> = ::init_static::InitStatic!(V5 : Config);
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_V1: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_V1() -> ::init_static::__private::Result<()> {
+            ::init_static::InitStatic::init(&V1, Some(42));
+            Ok(())
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&V1),
+            init: ::init_static::__private::InitFn::Sync(&INIT_V1),
+            deps: ::init_static::__private::Deps::Static(&[]),
+            weak_deps: &[],
+            unmanaged: &::std::vec::Vec::new,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_V2: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_V2() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&V0).__force();
+                (&V1).__force();
+            }
+            ::init_static::InitStatic::init(
+                &V2,
+                match *V1 {
+                    Some(N) if N > *V0 => N,
+                    _ => *V0,
+                },
+            );
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_V2: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&V0).__get_symbol() }, || unsafe { (&V1).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_V2() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [
+                    (&V0).__is_unmanaged().then_some("V0"),
+                    (&V1).__is_unmanaged().then_some("V1"),
+                ]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_V2() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&V2),
+            init: ::init_static::__private::InitFn::Sync(&INIT_V2),
+            deps: ::init_static::__private::Deps::Static(DEPS_V2),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_V2,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_V3: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_V3() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&V0).__force();
+                (&V1).__force();
+            }
+            ::init_static::InitStatic::init(
+                &V3,
+                if let Some(N) = *V1 { N } else { *V0 },
+            );
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_V3: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&V0).__get_symbol() }, || unsafe { (&V1).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_V3() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [
+                    (&V0).__is_unmanaged().then_some("V0"),
+                    (&V1).__is_unmanaged().then_some("V1"),
+                ]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_V3() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&V3),
+            init: ::init_static::__private::InitFn::Sync(&INIT_V3),
+            deps: ::init_static::__private::Deps::Static(DEPS_V3),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_V3,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_V4: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_V4() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&V2).__force();
+                (&V3).__force();
+            }
+            ::init_static::InitStatic::init(
+                &V4,
+                {
+                    let mut list = vec![* V2, * V3];
+                    let mut sum = 0;
+                    while let Some(N) = list.pop() {
+                        sum += N;
+                    }
+                    sum
+                },
+            );
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_V4: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&V2).__get_symbol() }, || unsafe { (&V3).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_V4() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [
+                    (&V2).__is_unmanaged().then_some("V2"),
+                    (&V3).__is_unmanaged().then_some("V3"),
+                ]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_V4() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&V4),
+            init: ::init_static::__private::InitFn::Sync(&INIT_V4),
+            deps: ::init_static::__private::Deps::Static(DEPS_V4),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_V4,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_BASE: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_BASE() -> ::init_static::__private::Result<()> {
+            ::init_static::InitStatic::init(&BASE, Config { a: 0, b: 0 });
+            Ok(())
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&BASE),
+            init: ::init_static::__private::InitFn::Sync(&INIT_BASE),
+            deps: ::init_static::__private::Deps::Static(&[]),
+            weak_deps: &[],
+            unmanaged: &::std::vec::Vec::new,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_V5: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_V5() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&BASE).__force();
+                (&V4).__force();
+            }
+            ::init_static::InitStatic::init(&V5, Config { a: *V4, ..*BASE });
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_V5: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&BASE).__get_symbol() }, || unsafe { (&V4).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_V5() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [
+                    (&BASE).__is_unmanaged().then_some("BASE"),
+                    (&V4).__is_unmanaged().then_some("V4"),
+                ]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_V5() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&V5),
+            init: ::init_static::__private::InitFn::Sync(&INIT_V5),
+            deps: ::init_static::__private::Deps::Static(DEPS_V5),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_V5,
+        }
+    };
+};
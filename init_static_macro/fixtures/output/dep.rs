@@ -0,0 +1,246 @@
+#[allow(unused_imports)]
+use init_static_macro::{init_static, init_thread_local};
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static NAMES: ::init_static::InitStatic<Vec<&'static str>> = ::init_static::InitStatic!(
+    NAMES : Vec < & 'static str >
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static COUNT: ::init_static::InitStatic<usize> = ::init_static::InitStatic!(
+    COUNT : usize
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static FIRST: ::init_static::InitStatic<&'static str> = ::init_static::InitStatic!(
+    FIRST : & 'static str
+);
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_NAMES: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_NAMES() -> ::init_static::__private::Result<()> {
+            ::init_static::InitStatic::init(&NAMES, vec!["foo", "bar"]);
+            Ok(())
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&NAMES),
+            init: ::init_static::__private::InitFn::Sync(&INIT_NAMES),
+            deps: ::init_static::__private::Deps::Static(&[]),
+            weak_deps: &[],
+            unmanaged: &::std::vec::Vec::new,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_COUNT: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_COUNT() -> ::init_static::__private::Result<()> {
+            fn __init_static_dependent() -> &'static ::init_static::Symbol {
+                ::init_static::InitStatic::symbol(&COUNT)
+            }
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&NAMES).__force();
+            }
+            ::init_static::InitStatic::init(&COUNT, init_static::dep!(NAMES).len());
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_COUNT: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&NAMES).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_COUNT() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [(&NAMES).__is_unmanaged().then_some("NAMES")]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_COUNT() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&COUNT),
+            init: ::init_static::__private::InitFn::Sync(&INIT_COUNT),
+            deps: ::init_static::__private::Deps::Static(DEPS_COUNT),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_COUNT,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_FIRST: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_FIRST() -> ::init_static::__private::BoxFuture<
+            ::init_static::__private::Result<()>,
+        > {
+            ::init_static::__private::require_async!(FIRST);
+            fn __init_static_dependent() -> &'static ::init_static::Symbol {
+                ::init_static::InitStatic::symbol(&FIRST)
+            }
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&NAMES).__force();
+            }
+            Box::pin(async {
+                ::init_static::InitStatic::init(
+                    &FIRST,
+                    async { init_static::dep!(NAMES)[0] }.await,
+                );
+                Ok(())
+            })
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_FIRST: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&NAMES).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_FIRST() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [(&NAMES).__is_unmanaged().then_some("NAMES")]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_FIRST() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&FIRST),
+            init: ::init_static::__private::InitFn::Async(&INIT_FIRST),
+            deps: ::init_static::__private::Deps::Static(DEPS_FIRST),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_FIRST,
+        }
+    };
+};
+#[rustfmt::skip]
+#[doc(hidden)]
+static __SYMBOL_LOCAL_COUNT: &::init_static::Symbol = ::init_static::Symbol!(
+    LOCAL_COUNT : usize
+);
+#[rustfmt::skip]
+::std::thread_local! {
+    #[allow(clippy::type_complexity)] static LOCAL_COUNT : ::init_static::InitThreadLocal
+    < usize > = const { ::init_static::InitThreadLocal::new(__SYMBOL_LOCAL_COUNT) };
+}
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(
+        ::init_static::__private::THREAD_LOCAL_INIT
+    )]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_LOCAL_COUNT: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_LOCAL_COUNT() -> ::init_static::__private::Result<()> {
+            fn __init_static_dependent() -> &'static ::init_static::Symbol {
+                __SYMBOL_LOCAL_COUNT
+            }
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&COUNT).__force();
+            }
+            let value = init_static::dep!(COUNT) + 1;
+            LOCAL_COUNT.with(|cell| ::init_static::InitThreadLocal::init(cell, value));
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_LOCAL_COUNT: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&COUNT).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_LOCAL_COUNT() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [(&COUNT).__is_unmanaged().then_some("COUNT")]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_LOCAL_COUNT() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: __SYMBOL_LOCAL_COUNT,
+            init: ::init_static::__private::InitFn::Sync(&INIT_LOCAL_COUNT),
+            deps: ::init_static::__private::Deps::Static(DEPS_LOCAL_COUNT),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_LOCAL_COUNT,
+        }
+    };
+};
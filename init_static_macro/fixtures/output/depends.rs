@@ -0,0 +1,149 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+fn helper() -> u32 {
+    *V1 + 1
+}
+#[rustfmt::skip]
+#[allow(clippy::type_complexity, dead_code)]
+static V1: ::init_static::InitStatic<u32> = ::init_static::InitStatic::with_value(
+    {
+        #[::init_static::__private::distributed_slice(
+            ::init_static::__private::CONST_SYMBOLS
+        )]
+        #[linkme(crate = ::init_static::__private::linkme)]
+        static SYMBOL: &::init_static::Symbol = ::init_static::Symbol!(V1 : u32);
+        SYMBOL
+    },
+    42,
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static V2: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V2 : u32);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static V3: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V3 : u32);
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_V2: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_V2() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&V1).__force();
+            }
+            ::init_static::InitStatic::init(&V2, helper());
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_V2: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&V1).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_V2() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [(&V1).__is_unmanaged().then_some("V1")].into_iter().flatten().collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_V2() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&V2),
+            init: ::init_static::__private::InitFn::Sync(&INIT_V2),
+            deps: ::init_static::__private::Deps::Static(DEPS_V2),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_V2,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_V3: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_V3() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&V1).__force();
+                (&self::V2).__force();
+            }
+            ::init_static::InitStatic::init(&V3, *V1 + helper());
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_V3: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[
+                || unsafe { (&V1).__get_symbol() },
+                || unsafe { (&self::V2).__get_symbol() },
+            ]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_V3() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [
+                    (&V1).__is_unmanaged().then_some("V1"),
+                    (&self::V2).__is_unmanaged().then_some("self::V2"),
+                ]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_V3() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&V3),
+            init: ::init_static::__private::InitFn::Sync(&INIT_V3),
+            deps: ::init_static::__private::Deps::Static(DEPS_V3),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_V3,
+        }
+    };
+};
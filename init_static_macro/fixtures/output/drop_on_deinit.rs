@@ -0,0 +1,164 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+fn flush(_: &Vec<u8>) {}
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static mut V1: ::init_static::InitStatic<Vec<u8>> = ::init_static::InitStatic!(
+    V1 : Vec < u8 >
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static mut V2: ::init_static::InitStatic<Vec<u8>> = ::init_static::InitStatic!(
+    V2 : Vec < u8 >
+);
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_V1: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_V1() -> ::init_static::__private::Result<()> {
+            ::init_static::InitStatic::init(
+                unsafe { &*::std::ptr::addr_of!(V1) },
+                Vec::new(),
+            );
+            Ok(())
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(unsafe {
+                &*::std::ptr::addr_of!(V1)
+            }),
+            init: ::init_static::__private::InitFn::Sync(&INIT_V1),
+            deps: ::init_static::__private::Deps::Static(&[]),
+            weak_deps: &[],
+            unmanaged: &::std::vec::Vec::new,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::DEINIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static DEINIT_V1: ::init_static::__private::Deinit = {
+        #[allow(non_snake_case, clippy::explicit_auto_deref)]
+        fn DEINIT_V1() {
+            ::std::mem::drop(
+                ::init_static::InitStatic::take(unsafe {
+                    &mut *::std::ptr::addr_of_mut!(V1)
+                }),
+            );
+        }
+        ::init_static::__private::Deinit {
+            symbol: ::init_static::InitStatic::symbol(unsafe {
+                &*::std::ptr::addr_of!(V1)
+            }),
+            deinit: ::init_static::__private::DeinitFn::Sync(DEINIT_V1),
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_V2: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_V2() -> ::init_static::__private::Result<()> {
+            ::init_static::InitStatic::init(
+                unsafe { &*::std::ptr::addr_of!(V2) },
+                Vec::new(),
+            );
+            Ok(())
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(unsafe {
+                &*::std::ptr::addr_of!(V2)
+            }),
+            init: ::init_static::__private::InitFn::Sync(&INIT_V2),
+            deps: ::init_static::__private::Deps::Static(&[]),
+            weak_deps: &[],
+            unmanaged: &::std::vec::Vec::new,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::DEINIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static DEINIT_V2: ::init_static::__private::Deinit = {
+        #[allow(non_snake_case, clippy::explicit_auto_deref)]
+        fn DEINIT_V2() {
+            (flush)(unsafe { &**::std::ptr::addr_of!(V2) });
+            ::std::mem::drop(
+                ::init_static::InitStatic::take(unsafe {
+                    &mut *::std::ptr::addr_of_mut!(V2)
+                }),
+            );
+        }
+        ::init_static::__private::Deinit {
+            symbol: ::init_static::InitStatic::symbol(unsafe {
+                &*::std::ptr::addr_of!(V2)
+            }),
+            deinit: ::init_static::__private::DeinitFn::Sync(DEINIT_V2),
+        }
+    };
+};
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static V3: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V3 : u32);
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_V3: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_V3() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&V1).__force();
+                (&V2).__force();
+            }
+            ::init_static::InitStatic::init(&V3, 42);
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_V3: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&V1).__get_symbol() }, || unsafe { (&V2).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_V3() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [
+                    (&V1).__is_unmanaged().then_some("V1"),
+                    (&V2).__is_unmanaged().then_some("V2"),
+                ]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_V3() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&V3),
+            init: ::init_static::__private::InitFn::Sync(&INIT_V3),
+            deps: ::init_static::__private::Deps::Static(DEPS_V3),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_V3,
+        }
+    };
+};
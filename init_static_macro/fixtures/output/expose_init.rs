@@ -0,0 +1,101 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+pub static V1: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V1 : u32);
+#[rustfmt::skip]
+/// Runs the initializer of [`V1`], unless it is already initialized.
+#[allow(non_snake_case)]
+pub fn init_V1() -> ::init_static::Result<()> {
+    #[allow(non_snake_case)]
+    fn INIT_V1() -> ::init_static::__private::Result<()> {
+        ::init_static::InitStatic::init(&V1, "1".parse()?);
+        Ok(())
+    }
+    if ::init_static::InitStatic::is_set(&V1) {
+        return Ok(());
+    }
+    INIT_V1()
+}
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static V2: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V2 : u32);
+#[rustfmt::skip]
+/// Runs the initializer of [`V2`], unless it is already initialized.
+#[allow(non_snake_case)]
+fn init_V2() -> ::init_static::InitFuture {
+    #[allow(non_snake_case)]
+    fn INIT_V2() -> ::init_static::__private::BoxFuture<
+        ::init_static::__private::Result<()>,
+    > {
+        ::init_static::__private::require_async!(V2);
+        #[allow(clippy::needless_borrow, static_mut_refs, unused_imports, unused_unsafe)]
+        unsafe {
+            use ::init_static::__private::MaybeInitStatic;
+            (&V1).__force();
+        }
+        Box::pin(async {
+            ::init_static::InitStatic::init(&V2, async { *V1 + 1 }.await);
+            Ok(())
+        })
+    }
+    if ::init_static::InitStatic::is_set(&V2) {
+        return Box::pin(async { Ok(()) });
+    }
+    INIT_V2()
+}
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_V1: ::init_static::__private::Init = {
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&V1),
+            init: ::init_static::__private::InitFn::Sync(&init_V1),
+            deps: ::init_static::__private::Deps::Static(&[]),
+            weak_deps: &[],
+            unmanaged: &::std::vec::Vec::new,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_V2: ::init_static::__private::Init = {
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_V2: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&V1).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_V2() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [(&V1).__is_unmanaged().then_some("V1")].into_iter().flatten().collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_V2() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&V2),
+            init: ::init_static::__private::InitFn::Async(&init_V2),
+            deps: ::init_static::__private::Deps::Static(DEPS_V2),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_V2,
+        }
+    };
+};
@@ -0,0 +1,239 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+const LEN: usize = 2;
+struct Window<const N: usize>;
+impl<const N: usize> Window<N> {
+    fn sum(values: &[u32]) -> u32 {
+        values.iter().take(N).sum()
+    }
+}
+#[allow(clippy::upper_case_acronyms)]
+struct UUID {
+    _v: u64,
+}
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static V1: ::init_static::InitStatic<Vec<u32>> = ::init_static::InitStatic!(
+    V1 : Vec < u32 >
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static V2: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V2 : u32);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static V3: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V3 : u32);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static IDS: ::init_static::InitStatic<Vec<UUID>> = ::init_static::InitStatic!(
+    IDS : Vec < UUID >
+);
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_V1: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_V1() -> ::init_static::__private::Result<()> {
+            ::init_static::InitStatic::init(&V1, Vec::<u32>::from([1, 2, 3]));
+            Ok(())
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&V1),
+            init: ::init_static::__private::InitFn::Sync(&INIT_V1),
+            deps: ::init_static::__private::Deps::Static(&[]),
+            weak_deps: &[],
+            unmanaged: &::std::vec::Vec::new,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_V2: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_V2() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&LEN).__force();
+                (&V1).__force();
+            }
+            ::init_static::InitStatic::init(&V2, Window::<{ LEN }>::sum(&V1));
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_V2: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&LEN).__get_symbol() }, || unsafe { (&V1).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_V2() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [
+                    (&LEN).__is_unmanaged().then_some("LEN"),
+                    (&V1).__is_unmanaged().then_some("V1"),
+                ]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_V2() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&V2),
+            init: ::init_static::__private::InitFn::Sync(&INIT_V2),
+            deps: ::init_static::__private::Deps::Static(DEPS_V2),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_V2,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_V3: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_V3() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&LEN).__force();
+                (&V1).__force();
+                (&V2).__force();
+            }
+            ::init_static::InitStatic::init(&V3, Window::<{ LEN + 1 }>::sum(&V1) + *V2);
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_V3: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[
+                || unsafe { (&LEN).__get_symbol() },
+                || unsafe { (&V1).__get_symbol() },
+                || unsafe { (&V2).__get_symbol() },
+            ]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_V3() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [
+                    (&LEN).__is_unmanaged().then_some("LEN"),
+                    (&V1).__is_unmanaged().then_some("V1"),
+                    (&V2).__is_unmanaged().then_some("V2"),
+                ]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_V3() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&V3),
+            init: ::init_static::__private::InitFn::Sync(&INIT_V3),
+            deps: ::init_static::__private::Deps::Static(DEPS_V3),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_V3,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_IDS: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_IDS() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&V2).__force();
+            }
+            ::init_static::InitStatic::init(
+                &IDS,
+                Vec::<UUID>::with_capacity(*V2 as usize),
+            );
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_IDS: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&V2).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_IDS() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [(&V2).__is_unmanaged().then_some("V2")].into_iter().flatten().collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_IDS() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&IDS),
+            init: ::init_static::__private::InitFn::Sync(&INIT_IDS),
+            deps: ::init_static::__private::Deps::Static(DEPS_IDS),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_IDS,
+        }
+    };
+};
@@ -0,0 +1,79 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+#[rustfmt::skip]
+#[allow(clippy::approx_constant)]
+#[allow(clippy::type_complexity, dead_code)]
+static PI: ::init_static::InitStatic<f64> = ::init_static::InitStatic::with_value(
+    {
+        #[::init_static::__private::distributed_slice(
+            ::init_static::__private::CONST_SYMBOLS
+        )]
+        #[linkme(crate = ::init_static::__private::linkme)]
+        static SYMBOL: &::init_static::Symbol = ::init_static::Symbol!(PI : f64);
+        SYMBOL
+    },
+    3.14,
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static ZERO: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(ZERO : u32);
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_ZERO: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        #[allow(clippy::identity_op, clippy::erasing_op)]
+        fn INIT_ZERO() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&PI).__force();
+            }
+            ::init_static::InitStatic::init(&ZERO, (*PI as u32) * 0 + 0);
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_ZERO: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&PI).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_ZERO() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [(&PI).__is_unmanaged().then_some("PI")].into_iter().flatten().collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_ZERO() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&ZERO),
+            init: ::init_static::__private::InitFn::Sync(&INIT_ZERO),
+            deps: ::init_static::__private::Deps::Static(DEPS_ZERO),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_ZERO,
+        }
+    };
+};
@@ -0,0 +1,35 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+#[rustfmt::skip]
+macro_rules! fetch {
+    ($future:expr) => {
+        $future . await
+    };
+}
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static V1: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(V1 : u32);
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_V1: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_V1() -> ::init_static::__private::BoxFuture<
+            ::init_static::__private::Result<()>,
+        > {
+            ::init_static::__private::require_async!(V1);
+            Box::pin(async {
+                ::init_static::InitStatic::init(&V1, fetch!(async { 42 }));
+                Ok(())
+            })
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&V1),
+            init: ::init_static::__private::InitFn::Async(&INIT_V1),
+            deps: ::init_static::__private::Deps::Static(&[]),
+            weak_deps: &[],
+            unmanaged: &::std::vec::Vec::new,
+        }
+    };
+};
@@ -0,0 +1,322 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+struct Config {
+    db: Db,
+}
+struct Db {
+    url: String,
+}
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static CONFIG: ::init_static::InitStatic<Config> = ::init_static::InitStatic!(
+    CONFIG : Config
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static NAMES: ::init_static::InitStatic<Vec<String>> = ::init_static::InitStatic!(
+    NAMES : Vec < String >
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static URL: ::init_static::InitStatic<String> = ::init_static::InitStatic!(URL : String);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static COUNT: ::init_static::InitStatic<usize> = ::init_static::InitStatic!(
+    COUNT : usize
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static FIRST: ::init_static::InitStatic<String> = ::init_static::InitStatic!(
+    FIRST : String
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static LEN: ::init_static::InitStatic<usize> = ::init_static::InitStatic!(LEN : usize);
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_CONFIG: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_CONFIG() -> ::init_static::__private::Result<()> {
+            ::init_static::InitStatic::init(
+                &CONFIG,
+                Config {
+                    db: Db {
+                        url: "postgres://localhost".parse()?,
+                    },
+                },
+            );
+            Ok(())
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&CONFIG),
+            init: ::init_static::__private::InitFn::Sync(&INIT_CONFIG),
+            deps: ::init_static::__private::Deps::Static(&[]),
+            weak_deps: &[],
+            unmanaged: &::std::vec::Vec::new,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_NAMES: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_NAMES() -> ::init_static::__private::Result<()> {
+            ::init_static::InitStatic::init(
+                &NAMES,
+                "foo,bar".split(',').map(String::from).collect(),
+            );
+            Ok(())
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&NAMES),
+            init: ::init_static::__private::InitFn::Sync(&INIT_NAMES),
+            deps: ::init_static::__private::Deps::Static(&[]),
+            weak_deps: &[],
+            unmanaged: &::std::vec::Vec::new,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_URL: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_URL() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&CONFIG).__force();
+            }
+            ::init_static::InitStatic::init(&URL, CONFIG.db.url.clone());
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_URL: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&CONFIG).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_URL() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [(&CONFIG).__is_unmanaged().then_some("CONFIG")]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_URL() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&URL),
+            init: ::init_static::__private::InitFn::Sync(&INIT_URL),
+            deps: ::init_static::__private::Deps::Static(DEPS_URL),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_URL,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_COUNT: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_COUNT() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&NAMES).__force();
+            }
+            ::init_static::InitStatic::init(&COUNT, NAMES.iter().count());
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_COUNT: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&NAMES).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_COUNT() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [(&NAMES).__is_unmanaged().then_some("NAMES")]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_COUNT() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&COUNT),
+            init: ::init_static::__private::InitFn::Sync(&INIT_COUNT),
+            deps: ::init_static::__private::Deps::Static(DEPS_COUNT),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_COUNT,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_FIRST: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_FIRST() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&COUNT).__force();
+                (&NAMES).__force();
+            }
+            ::init_static::InitStatic::init(&FIRST, NAMES[*COUNT - 2].to_uppercase());
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_FIRST: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[
+                || unsafe { (&COUNT).__get_symbol() },
+                || unsafe { (&NAMES).__get_symbol() },
+            ]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_FIRST() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [
+                    (&COUNT).__is_unmanaged().then_some("COUNT"),
+                    (&NAMES).__is_unmanaged().then_some("NAMES"),
+                ]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_FIRST() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&FIRST),
+            init: ::init_static::__private::InitFn::Sync(&INIT_FIRST),
+            deps: ::init_static::__private::Deps::Static(DEPS_FIRST),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_FIRST,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_LEN: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_LEN() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&URL).__force();
+            }
+            ::init_static::InitStatic::init(&LEN, (*URL).len());
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_LEN: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&URL).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_LEN() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [(&URL).__is_unmanaged().then_some("URL")]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_LEN() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&LEN),
+            init: ::init_static::__private::InitFn::Sync(&INIT_LEN),
+            deps: ::init_static::__private::Deps::Static(DEPS_LEN),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_LEN,
+        }
+    };
+};
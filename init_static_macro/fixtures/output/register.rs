@@ -0,0 +1,99 @@
+#[allow(unused_imports)]
+use init_static_macro::register;
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static BASE: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(BASE : u32);
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_BASE: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_BASE() -> ::init_static::__private::Result<()> {
+            ::init_static::InitStatic::init(&BASE, "42".parse()?);
+            Ok(())
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&BASE),
+            init: ::init_static::__private::InitFn::Sync(&INIT_BASE),
+            deps: ::init_static::__private::Deps::Static(&[]),
+            weak_deps: &[],
+            unmanaged: &::std::vec::Vec::new,
+        }
+    };
+};
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static DERIVED: ::init_static::InitStatic<u32> = ::init_static::InitStatic!(
+    DERIVED : u32
+);
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_DERIVED: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_DERIVED() -> ::init_static::__private::BoxFuture<
+            ::init_static::__private::Result<()>,
+        > {
+            ::init_static::__private::require_async!(DERIVED);
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&BASE).__force();
+            }
+            Box::pin(async {
+                ::init_static::InitStatic::init(
+                    &DERIVED,
+                    async { BASE.checked_add(1).unwrap() }.await,
+                );
+                Ok(())
+            })
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_DERIVED: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&BASE).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_DERIVED() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [(&BASE).__is_unmanaged().then_some("BASE")]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_DERIVED() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&DERIVED),
+            init: ::init_static::__private::InitFn::Async(&INIT_DERIVED),
+            deps: ::init_static::__private::Deps::Static(DEPS_DERIVED),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_DERIVED,
+        }
+    };
+};
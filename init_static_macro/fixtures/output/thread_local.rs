@@ -0,0 +1,107 @@
+#[allow(unused_imports)]
+use init_static_macro::init_thread_local;
+#[rustfmt::skip]
+#[doc(hidden)]
+static __SYMBOL_CAPACITY: &::init_static::Symbol = ::init_static::Symbol!(
+    CAPACITY : usize
+);
+#[rustfmt::skip]
+::std::thread_local! {
+    #[doc = " The capacity of the buffer."] #[allow(clippy::type_complexity)] static
+    CAPACITY : ::init_static::InitThreadLocal < usize > = const {
+    ::init_static::InitThreadLocal::new(__SYMBOL_CAPACITY) };
+}
+#[rustfmt::skip]
+#[doc(hidden)]
+static __SYMBOL_BUFFER: &::init_static::Symbol = ::init_static::Symbol!(
+    BUFFER : Vec < u8 >
+);
+#[rustfmt::skip]
+::std::thread_local! {
+    #[allow(clippy::type_complexity)] pub static BUFFER : ::init_static::InitThreadLocal
+    < Vec < u8 > > = const { ::init_static::InitThreadLocal::new(__SYMBOL_BUFFER) };
+}
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(
+        ::init_static::__private::THREAD_LOCAL_INIT
+    )]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_CAPACITY: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_CAPACITY() -> ::init_static::__private::Result<()> {
+            let value = "1024".parse()?;
+            CAPACITY.with(|cell| ::init_static::InitThreadLocal::init(cell, value));
+            Ok(())
+        }
+        ::init_static::__private::Init {
+            symbol: __SYMBOL_CAPACITY,
+            init: ::init_static::__private::InitFn::Sync(&INIT_CAPACITY),
+            deps: ::init_static::__private::Deps::Static(&[]),
+            weak_deps: &[],
+            unmanaged: &::std::vec::Vec::new,
+        }
+    };
+    #[::init_static::__private::distributed_slice(
+        ::init_static::__private::THREAD_LOCAL_INIT
+    )]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_BUFFER: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_BUFFER() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&CAPACITY).__force();
+            }
+            let value = Vec::with_capacity(CAPACITY.with(|capacity| **capacity));
+            BUFFER.with(|cell| ::init_static::InitThreadLocal::init(cell, value));
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_BUFFER: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&CAPACITY).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_BUFFER() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [(&CAPACITY).__is_unmanaged().then_some("CAPACITY")]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_BUFFER() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: __SYMBOL_BUFFER,
+            init: ::init_static::__private::InitFn::Sync(&INIT_BUFFER),
+            deps: ::init_static::__private::Deps::Static(DEPS_BUFFER),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_BUFFER,
+        }
+    };
+};
@@ -0,0 +1,240 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static HOST: ::init_static::InitStatic<String> = ::init_static::InitStatic!(
+    HOST : String
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static PORT: ::init_static::InitStatic<u16> = ::init_static::InitStatic!(PORT : u16);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static ADDR: ::init_static::InitStatic<String> = ::init_static::InitStatic!(
+    ADDR : String
+);
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static LEN: ::init_static::InitStatic<usize> = ::init_static::InitStatic!(LEN : usize);
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_HOST: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_HOST() -> ::init_static::__private::Result<()> {
+            ::init_static::InitStatic::init(
+                &HOST,
+                {
+                    let (__value_0, __value_1) = ("localhost".to_string(), 8080);
+                    ::init_static::InitStatic::init(&PORT, __value_1);
+                    __value_0
+                },
+            );
+            Ok(())
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&HOST),
+            init: ::init_static::__private::InitFn::Sync(&INIT_HOST),
+            deps: ::init_static::__private::Deps::Static(&[]),
+            weak_deps: &[],
+            unmanaged: &::std::vec::Vec::new,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_PORT: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_PORT() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&HOST).__force();
+            }
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_PORT: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&HOST).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_PORT() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [(&HOST).__is_unmanaged().then_some("HOST")]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_PORT() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&PORT),
+            init: ::init_static::__private::InitFn::Sync(&INIT_PORT),
+            deps: ::init_static::__private::Deps::Static(DEPS_PORT),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_PORT,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_ADDR: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_ADDR() -> ::init_static::__private::BoxFuture<
+            ::init_static::__private::Result<()>,
+        > {
+            ::init_static::__private::require_async!(ADDR);
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&HOST).__force();
+                (&PORT).__force();
+            }
+            Box::pin(async {
+                ::init_static::InitStatic::init(
+                    &ADDR,
+                    {
+                        let (__value_0, __value_1) = async {
+                            let addr = format!("{}:{}", * HOST, * PORT);
+                            let len = addr.len();
+                            (addr, len)
+                        }
+                            .await;
+                        ::init_static::InitStatic::init(&LEN, __value_1);
+                        __value_0
+                    },
+                );
+                Ok(())
+            })
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_ADDR: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&HOST).__get_symbol() }, || unsafe { (&PORT).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_ADDR() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [
+                    (&HOST).__is_unmanaged().then_some("HOST"),
+                    (&PORT).__is_unmanaged().then_some("PORT"),
+                ]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_ADDR() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&ADDR),
+            init: ::init_static::__private::InitFn::Async(&INIT_ADDR),
+            deps: ::init_static::__private::Deps::Static(DEPS_ADDR),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_ADDR,
+        }
+    };
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_LEN: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_LEN() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&ADDR).__force();
+            }
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_LEN: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&ADDR).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_LEN() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [(&ADDR).__is_unmanaged().then_some("ADDR")]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_LEN() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&LEN),
+            init: ::init_static::__private::InitFn::Sync(&INIT_LEN),
+            deps: ::init_static::__private::Deps::Static(DEPS_LEN),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_LEN,
+        }
+    };
+};
@@ -0,0 +1,72 @@
+#[allow(unused_imports)]
+use init_static_macro::init_static;
+pub mod sizes {
+    pub const BUF_LEN: usize = 16;
+}
+#[rustfmt::skip]
+#[allow(clippy::type_complexity)]
+static BUF: ::init_static::InitStatic<[u8; sizes::BUF_LEN]> = ::init_static::InitStatic!(
+    BUF : [u8; sizes::BUF_LEN]
+);
+#[rustfmt::skip]
+const _: () = {
+    #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+    #[linkme(crate = ::init_static::__private::linkme)]
+    static INIT_BUF: ::init_static::__private::Init = {
+        #[allow(non_snake_case)]
+        fn INIT_BUF() -> ::init_static::__private::Result<()> {
+            #[allow(
+                clippy::needless_borrow,
+                static_mut_refs,
+                unused_imports,
+                unused_unsafe
+            )]
+            unsafe {
+                use ::init_static::__private::MaybeInitStatic;
+                (&sizes::BUF_LEN).__force();
+            }
+            ::init_static::InitStatic::init(&BUF, Default::default());
+            Ok(())
+        }
+        #[allow(
+            non_upper_case_globals,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        static DEPS_BUF: &[::init_static::__private::DepFn] = {
+            use ::init_static::__private::MaybeInitStatic;
+            &[|| unsafe { (&sizes::BUF_LEN).__get_symbol() }]
+        };
+        #[cfg(debug_assertions)]
+        #[allow(
+            non_snake_case,
+            clippy::needless_borrow,
+            static_mut_refs,
+            unused_imports,
+            unused_unsafe
+        )]
+        fn UNMANAGED_BUF() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe {
+                [(&sizes::BUF_LEN).__is_unmanaged().then_some("sizes::BUF_LEN")]
+                    .into_iter()
+                    .flatten()
+                    .collect()
+            }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn UNMANAGED_BUF() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+        ::init_static::__private::Init {
+            symbol: ::init_static::InitStatic::symbol(&BUF),
+            init: ::init_static::__private::InitFn::Sync(&INIT_BUF),
+            deps: ::init_static::__private::Deps::Static(DEPS_BUF),
+            weak_deps: &[],
+            unmanaged: &UNMANAGED_BUF,
+        }
+    };
+};
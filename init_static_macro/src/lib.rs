@@ -1,9 +1,10 @@
 use std::collections::{BTreeSet, HashSet};
 
 use proc_macro::TokenStream;
-use proc_macro2::TokenStream as TokenStream2;
-use quote::{quote, quote_spanned};
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use quote::{ToTokens, quote, quote_spanned};
 use syn::parse::{Parse, ParseStream, Parser};
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::visit::Visit;
 
@@ -12,93 +13,579 @@ pub fn init_static(input: TokenStream) -> TokenStream {
     init_static_inner(input.into()).into()
 }
 
-fn parse_repeated<T: Parse>(tokens: TokenStream2) -> syn::Result<Vec<T>> {
+#[proc_macro]
+pub fn init_thread_local(input: TokenStream) -> TokenStream {
+    init_thread_local_inner(input.into()).into()
+}
+
+#[proc_macro_attribute]
+pub fn register(attr: TokenStream, item: TokenStream) -> TokenStream {
+    register_inner(attr.into(), item.into()).into()
+}
+
+#[doc(hidden)]
+#[proc_macro_attribute]
+pub fn __distributed_slice(attr: TokenStream, item: TokenStream) -> TokenStream {
+    distributed_slice_inner(attr.into(), item.into()).into()
+}
+
+fn parse_repeated<T: Parse>(tokens: TokenStream2) -> syn::Result<(Vec<syn::Attribute>, Vec<T>)> {
     let parser = |input: ParseStream| {
+        let attrs = input.call(syn::Attribute::parse_inner)?;
         let mut items = vec![];
         while !input.is_empty() {
             items.push(input.parse::<T>()?);
         }
-        Ok(items)
+        Ok((attrs, items))
     };
     parser.parse2(tokens)
 }
 
+/// Options specified via inner attributes at the top of an `init_static!` block.
+#[derive(Default)]
+struct BlockOptions {
+    /// Whether `#![any_case_deps]` is present.
+    any_case_deps: bool,
+}
+
+impl BlockOptions {
+    fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut options = Self::default();
+        for attr in attrs {
+            if attr.path().is_ident("any_case_deps") {
+                attr.meta.require_path_only()?;
+                options.any_case_deps = true;
+            } else {
+                return Err(syn::Error::new_spanned(attr, "unsupported inner attribute"));
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// A static declaring several statics at once from a tuple, as
+/// `static (A, B): (TA, TB) = expr;`.
+struct TupleStatic {
+    attrs: Vec<syn::Attribute>,
+    vis: syn::Visibility,
+    static_token: syn::Token![static],
+    idents: Vec<syn::Ident>,
+    colon_token: syn::Token![:],
+    ty: syn::TypeTuple,
+    eq_token: syn::Token![=],
+    expr: Box<syn::Expr>,
+    semi_token: syn::Token![;],
+}
+
+impl Parse for TupleStatic {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(syn::Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let static_token = input.parse()?;
+        let content;
+        let paren_token = syn::parenthesized!(content in input);
+        let idents = Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated(&content)?;
+        let colon_token = input.parse()?;
+        let ty: syn::TypeTuple = input.parse()?;
+        if idents.len() < 2 || idents.len() != ty.elems.len() {
+            return Err(syn::Error::new(
+                paren_token.span.join(),
+                "a tuple static must declare at least two statics, one for each element of its type",
+            ));
+        }
+        Ok(Self {
+            attrs,
+            vis,
+            static_token,
+            idents: idents.into_iter().collect(),
+            colon_token,
+            ty,
+            eq_token: input.parse()?,
+            expr: input.parse()?,
+            semi_token: parse_semi(input)?,
+        })
+    }
+}
+
+/// Parses a static, which is parsed by hand rather than as a [`syn::Item`] to accept the
+/// variations of [`parse_semi`].
+fn parse_static(input: ParseStream) -> syn::Result<syn::ItemStatic> {
+    Ok(syn::ItemStatic {
+        attrs: input.call(syn::Attribute::parse_outer)?,
+        vis: input.parse()?,
+        static_token: input.parse()?,
+        mutability: input.parse()?,
+        ident: input.parse()?,
+        colon_token: input.parse()?,
+        ty: input.parse()?,
+        eq_token: input.parse()?,
+        expr: input.parse()?,
+        semi_token: parse_semi(input)?,
+    })
+}
+
+/// Parses the semicolon terminating a static. As code generators often emit them, a comma is
+/// accepted in its place, and it may be omitted at the end of the block or of a braced group.
+fn parse_semi(input: ParseStream) -> syn::Result<syn::Token![;]> {
+    if input.is_empty() {
+        return Ok(Default::default());
+    }
+    if let Some(comma) = input.parse::<Option<syn::Token![,]>>()? {
+        return Ok(syn::Token![;](comma.span));
+    }
+    if !input.peek(syn::Token![;]) {
+        return Err(input.error("expected `;` after the initializer of a static"));
+    }
+    input.parse()
+}
+
+/// An item inside an `init_static!` block.
+enum BlockItem {
+    Item(syn::Item),
+    Tuple(TupleStatic),
+    /// Items grouped in braces, which are expanded as if they were not.
+    Group(Vec<BlockItem>),
+}
+
+impl Parse for BlockItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::token::Brace) {
+            let content;
+            syn::braced!(content in input);
+            let mut items = vec![];
+            while !content.is_empty() {
+                items.push(content.parse()?);
+            }
+            return Ok(Self::Group(items));
+        }
+        let fork = input.fork();
+        fork.call(syn::Attribute::parse_outer)?;
+        fork.parse::<syn::Visibility>()?;
+        if fork.peek(syn::Token![static]) && fork.peek2(syn::token::Paren) {
+            input.parse().map(Self::Tuple)
+        } else if fork.peek(syn::Token![static]) {
+            parse_static(input).map(|item| Self::Item(syn::Item::Static(item)))
+        } else if fork.peek(syn::Token![let]) {
+            Err(fork.error("expected a static or an item, found a `let` statement"))
+        } else {
+            input.parse().map(Self::Item)
+        }
+    }
+}
+
+impl BlockItem {
+    /// Flattens braced groups into `items`.
+    fn flatten_into(self, items: &mut Vec<BlockItem>) {
+        match self {
+            Self::Group(group) => group.into_iter().for_each(|item| item.flatten_into(items)),
+            item => items.push(item),
+        }
+    }
+}
+
 pub(crate) fn init_static_inner(input: TokenStream2) -> TokenStream2 {
-    let input_items = match parse_repeated::<syn::Item>(input) {
-        Ok(items) => items,
+    expand_block(input, expand_static, Some(expand_tuple_static))
+}
+
+pub(crate) fn init_thread_local_inner(input: TokenStream2) -> TokenStream2 {
+    expand_block(input, expand_thread_local, None)
+}
+
+/// The expansion of a single static, which appends the declaration to `output` and its registry
+/// entries to `inner`.
+type ExpandFn = fn(syn::ItemStatic, bool, &mut TokenStream2, &mut TokenStream2) -> syn::Result<()>;
+
+/// The expansion of a tuple static, if supported.
+type ExpandTupleFn = fn(TupleStatic, bool, &mut TokenStream2, &mut TokenStream2) -> syn::Result<()>;
+
+fn expand_block(input: TokenStream2, expand: ExpandFn, expand_tuple: Option<ExpandTupleFn>) -> TokenStream2 {
+    let (block_options, input_items) =
+        match parse_repeated::<BlockItem>(input).and_then(|(attrs, items)| Ok((BlockOptions::parse(&attrs)?, items))) {
+            Ok(result) => result,
+            Err(err) => return err.to_compile_error(),
+        };
+
+    let mut items = vec![];
+    for item in input_items {
+        item.flatten_into(&mut items);
+    }
+
+    let mut output = TokenStream2::new();
+    let mut inner = TokenStream2::new();
+
+    for item in items {
+        let result = match item {
+            BlockItem::Item(syn::Item::Static(item_static)) => {
+                expand(item_static, block_options.any_case_deps, &mut output, &mut inner)
+            }
+            BlockItem::Item(item) => {
+                output.extend(quote! { #item });
+                continue;
+            }
+            BlockItem::Tuple(item) => match expand_tuple {
+                Some(expand_tuple) => expand_tuple(item, block_options.any_case_deps, &mut output, &mut inner),
+                None => Err(syn::Error::new_spanned(
+                    &item.ty,
+                    "tuple statics are not supported here",
+                )),
+            },
+            BlockItem::Group(_) => unreachable!("groups are flattened above"),
+        };
+        if let Err(err) = result {
+            return err.to_compile_error();
+        }
+    }
+
+    quote! {
+        #output
+
+        const _: () = {
+            #inner
+        };
+    }
+}
+
+pub(crate) fn register_inner(attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
+    if !attr.is_empty() {
+        return syn::Error::new_spanned(attr, "unexpected arguments").to_compile_error();
+    }
+    let item_static = match syn::parse2::<syn::ItemStatic>(item) {
+        Ok(item_static) => item_static,
         Err(err) => return err.to_compile_error(),
     };
 
     let mut output = TokenStream2::new();
     let mut inner = TokenStream2::new();
+    if let Err(err) = expand_static(item_static, false, &mut output, &mut inner) {
+        return err.to_compile_error();
+    }
 
-    for item in input_items {
-        let syn::Item::Static(item_static) = item else {
-            output.extend(quote! { #item });
-            continue;
+    quote! {
+        #output
+
+        const _: () = {
+            #inner
         };
+    }
+}
 
-        let mut is_try = false;
-        let mut is_async = false;
-        let mut free_paths = BTreeSet::new();
-        let mut scope = Scope {
-            is_try: &mut is_try,
-            is_async: &mut is_async,
-            free_paths: &mut free_paths,
-            locals: HashSet::new(),
+/// The constructor-based counterpart of `linkme::distributed_slice`, which `init_static` exports
+/// in its place with `--cfg init_static_ctor` and on WebAssembly. The static is kept as is, and
+/// pushed to the registry by a constructor that runs before `main`.
+fn distributed_slice_inner(attr: TokenStream2, item: TokenStream2) -> TokenStream2 {
+    let registry = match syn::parse2::<syn::Path>(attr) {
+        Ok(registry) => registry,
+        Err(err) => return err.to_compile_error(),
+    };
+    let mut item_static = match syn::parse2::<syn::ItemStatic>(item) {
+        Ok(item_static) => item_static,
+        Err(err) => return err.to_compile_error(),
+    };
+    item_static.attrs.retain(|attr| !attr.path().is_ident("linkme"));
+    let item_ident = &item_static.ident;
+    let cfg_attrs = item_static.attrs.iter().filter(|attr| attr.path().is_ident("cfg"));
+    quote! {
+        #item_static
+        #(#cfg_attrs)*
+        ::init_static::__private::register!(#registry, #item_ident);
+    }
+}
+
+/// Expands a single static, appending the wrapped static to `output` and its registry entries to
+/// `inner`, which is placed inside an anonymous `const` block.
+fn expand_static(
+    mut item_static: syn::ItemStatic,
+    any_case_deps: bool,
+    output: &mut TokenStream2,
+    inner: &mut TokenStream2,
+) -> syn::Result<()> {
+    let options = StaticOptions::take(&mut item_static.attrs)?;
+    expand_static_with(item_static, options, any_case_deps, output, inner)
+}
+
+/// Expands a tuple static such as `static (A, B): (TA, TB) = expr;`, whose init expression runs
+/// once and initializes every static from the resulting tuple.
+///
+/// The first static is initialized by the expression, and initializes the others along the way.
+/// The others are registered with an init function that does nothing but depend on the first, so
+/// that their dependents are still ordered correctly.
+fn expand_tuple_static(
+    mut item: TupleStatic,
+    any_case_deps: bool,
+    output: &mut TokenStream2,
+    inner: &mut TokenStream2,
+) -> syn::Result<()> {
+    let mut options = StaticOptions::take(&mut item.attrs)?;
+    let (first, rest) = item.idents.split_first().expect("tuple statics are not empty");
+    let values = (0..item.idents.len())
+        .map(|i| syn::Ident::new(&format!("__value_{i}"), Span::mixed_site()))
+        .collect::<Vec<_>>();
+    let expr = &item.expr;
+    let (first_value, rest_values) = values.split_first().unwrap();
+    let first_static = syn::ItemStatic {
+        attrs: item.attrs.clone(),
+        vis: item.vis.clone(),
+        static_token: item.static_token,
+        mutability: syn::StaticMutability::None,
+        ident: first.clone(),
+        colon_token: item.colon_token,
+        ty: Box::new(item.ty.elems[0].clone()),
+        eq_token: item.eq_token,
+        expr: syn::parse_quote! {{
+            let (#(#values),*) = #expr;
+            #(::init_static::InitStatic::init(&#rest, #rest_values);)*
+            #first_value
+        }},
+        semi_token: item.semi_token,
+    };
+    options
+        .ignore_deps
+        .extend(rest.iter().map(|ident| syn::Path::from(ident.clone())));
+    expand_static_with(first_static, options, any_case_deps, output, inner)?;
+    for (ident, ty) in rest.iter().zip(item.ty.elems.iter().skip(1)) {
+        let item_static = syn::ItemStatic {
+            attrs: item.attrs.clone(),
+            vis: item.vis.clone(),
+            static_token: item.static_token,
+            mutability: syn::StaticMutability::None,
+            ident: ident.clone(),
+            colon_token: item.colon_token,
+            ty: Box::new(ty.clone()),
+            eq_token: item.eq_token,
+            expr: syn::parse_quote! { () },
+            semi_token: item.semi_token,
         };
-        scope.visit_item_static(&item_static);
-
-        let item_vis = &item_static.vis;
-        let item_ident = &item_static.ident;
-        let item_mut = &item_static.mutability;
-        let item_ty = &item_static.ty;
-        let item_expr = &item_static.expr;
-        let ty_span = item_ty.span();
-        let ident_span = item_ident.span();
-        let static_ty = quote_spanned! { ty_span =>
-            ::init_static::InitStatic<#item_ty>
+        let options = StaticOptions {
+            depends: vec![first.clone().into()],
+            no_deps: true,
+            initialized_by: Some(first.clone()),
+            ..Default::default()
         };
-        let static_expr = quote_spanned! { ident_span =>
-            ::init_static::InitStatic!(#item_ident)
+        expand_static_with(item_static, options, any_case_deps, output, inner)?;
+    }
+    Ok(())
+}
+
+/// Expands a single static whose helper attributes have already been collected into `options`.
+fn expand_static_with(
+    item_static: syn::ItemStatic,
+    options: StaticOptions,
+    any_case_deps: bool,
+    output: &mut TokenStream2,
+    inner: &mut TokenStream2,
+) -> syn::Result<()> {
+    let (is_async, free_paths) = scan_static(&item_static, &options, any_case_deps);
+
+    let item_vis = &item_static.vis;
+    let item_ident = &item_static.ident;
+    let item_mut = &item_static.mutability;
+    let item_ty = &item_static.ty;
+    let item_expr = &item_static.expr;
+    let ty_span = item_ty.span();
+    let ident_span = item_ident.span();
+    let static_ty = quote_spanned! { ty_span =>
+        ::init_static::InitStatic<#item_ty>
+    };
+    let item_attrs = &item_static.attrs;
+    let init_allow = &options.init_allow;
+
+    // A static initialized by a literal is initialized at compile time, with no init function. Only
+    // its symbol is registered, so that dependencies on it are satisfied.
+    if options.is_plain() && matches!(item_mut, syn::StaticMutability::None) && is_const_expr(item_expr) {
+        let symbol = quote_spanned! { ident_span =>
+            {
+                #[::init_static::__private::distributed_slice(::init_static::__private::CONST_SYMBOLS)]
+                #[linkme(crate = ::init_static::__private::linkme)]
+                static SYMBOL: &::init_static::Symbol = ::init_static::Symbol!(#item_ident: #item_ty);
+                SYMBOL
+            }
         };
         output.extend(quote! {
-            #[allow(clippy::type_complexity)]
-            #item_vis static #item_mut #item_ident: #static_ty = #static_expr;
+            #(#item_attrs)*
+            #(#[allow(#init_allow)])*
+            #[allow(clippy::type_complexity, dead_code)]
+            #item_vis static #item_ident: #static_ty = ::init_static::InitStatic::with_value(#symbol, #item_expr);
         });
+        return Ok(());
+    }
 
-        let (deps_ident, deps_item) = if free_paths.is_empty() {
-            (quote! { ::std::vec::Vec::new }, quote! {})
-        } else {
-            let deps_ident = syn::Ident::new(&format!("DEPS_{item_ident}"), ident_span);
-            let deps_stmts = free_paths.iter().map(|path| {
-                let path = &path.path;
-                quote! {
-                    (&#path).__get_symbol()
+    let static_expr = quote_spanned! { ident_span =>
+        ::init_static::InitStatic!(#item_ident: #item_ty)
+    };
+    output.extend(quote! {
+        #(#item_attrs)*
+        #[allow(clippy::type_complexity)]
+        #item_vis static #item_mut #item_ident: #static_ty = #static_expr;
+    });
+
+    // The registry entries must be compiled out along with the static itself.
+    let cfg_attrs = item_static
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+        .collect::<Vec<_>>();
+
+    // References to a `static mut` must go through a raw pointer.
+    let (item_ref, value_ref) = match item_mut {
+        syn::StaticMutability::Mut(_) => (
+            quote! { unsafe { &*::std::ptr::addr_of!(#item_ident) } },
+            quote! { unsafe { &**::std::ptr::addr_of!(#item_ident) } },
+        ),
+        _ => (quote! { &#item_ident }, quote! { &*#item_ident }),
+    };
+
+    let (deps, unmanaged_ident, deps_item) = deps_fn(item_ident, &free_paths, &options);
+    let (weak_deps, weak_deps_item) = weak_deps_fn(item_ident, &options);
+    let force_deps = force_deps(&free_paths);
+    let dependent_fn = dependent_fn(item_expr, quote! { ::init_static::InitStatic::symbol(#item_ref) });
+    let context_stmts = options.context.as_ref().map(|ContextAttr { ident, ty }| {
+        quote! {
+            let #ident = ::init_static::__private::context::<#ty>(::init_static::InitStatic::symbol(#item_ref))?;
+            let #ident: &#ty = &#ident;
+        }
+    });
+
+    // A static initialized along with another one of a tuple static only waits for it.
+    let init_stmt = options.initialized_by.is_none().then(|| {
+        quote! {
+            ::init_static::InitStatic::init(#item_ref, #item_expr);
+        }
+    });
+
+    // The registry entries are scoped to the anonymous `const _` block of this invocation, so their
+    // names cannot collide with those generated by another invocation for a static of the same name,
+    // e.g. when the same file is included in several modules.
+    let init_ident = syn::Ident::new(&format!("INIT_{item_ident}"), item_ident.span());
+    let is_async = is_async || options.init_async;
+    let require_async = quote_spanned! { ident_span =>
+        ::init_static::__private::require_async!(#item_ident);
+    };
+    let (init_variant, mut init_item) = if is_async {
+        (
+            quote! { Async },
+            quote! {
+                #[allow(non_snake_case)]
+                #(#[allow(#init_allow)])*
+                fn #init_ident() -> ::init_static::__private::BoxFuture<::init_static::__private::Result<()>> {
+                    #require_async
+                    #dependent_fn
+                    #force_deps
+                    Box::pin(async {
+                        #context_stmts
+                        #init_stmt
+                        Ok(())
+                    })
                 }
-            });
-            (
-                quote! { #deps_ident },
-                quote! {
-                    #[allow(non_snake_case, clippy::needless_borrow)]
-                    fn #deps_ident() -> ::std::vec::Vec<::std::option::Option<&'static ::init_static::Symbol>> {
-                        use ::init_static::__private::MaybeInitStatic;
-                        ::std::vec![#(#deps_stmts),*]
+            },
+        )
+    } else {
+        (
+            quote! { Sync },
+            quote! {
+                #[allow(non_snake_case)]
+                #(#[allow(#init_allow)])*
+                fn #init_ident() -> ::init_static::__private::Result<()> {
+                    #dependent_fn
+                    #force_deps
+                    #context_stmts
+                    #init_stmt
+                    Ok(())
+                }
+            },
+        )
+    };
+
+    // An exposed init function is declared next to the static, and registered in place of the
+    // hidden one, which it calls unless the static is already initialized.
+    let mut init_ref = quote! { #init_ident };
+    if options.expose_init.is_some() {
+        let expose_ident = syn::Ident::new(&format!("init_{item_ident}"), item_ident.span());
+        let doc = format!(" Runs the initializer of [`{item_ident}`], unless it is already initialized.");
+        let expose_item = if is_async {
+            quote! {
+                #[doc = #doc]
+                #(#cfg_attrs)*
+                #[allow(non_snake_case)]
+                #item_vis fn #expose_ident() -> ::init_static::InitFuture {
+                    #init_item
+                    if ::init_static::InitStatic::is_set(#item_ref) {
+                        return Box::pin(async { Ok(()) });
                     }
-                },
-            )
+                    #init_ident()
+                }
+            }
+        } else {
+            quote! {
+                #[doc = #doc]
+                #(#cfg_attrs)*
+                #[allow(non_snake_case)]
+                #item_vis fn #expose_ident() -> ::init_static::Result<()> {
+                    #init_item
+                    if ::init_static::InitStatic::is_set(#item_ref) {
+                        return Ok(());
+                    }
+                    #init_ident()
+                }
+            }
+        };
+        output.extend(expose_item);
+        init_item = quote! {};
+        init_ref = quote! { #expose_ident };
+    }
+
+    inner.extend(quote! {
+        #(#cfg_attrs)*
+        #[::init_static::__private::distributed_slice(::init_static::__private::INIT)]
+        #[linkme(crate = ::init_static::__private::linkme)]
+        static #init_ident: ::init_static::__private::Init = {
+            #init_item
+            #deps_item
+            #weak_deps_item
+            ::init_static::__private::Init {
+                symbol: ::init_static::InitStatic::symbol(#item_ref),
+                init: ::init_static::__private::InitFn::#init_variant(&#init_ref),
+                deps: #deps,
+                weak_deps: #weak_deps,
+                unmanaged: &#unmanaged_ident,
+            }
         };
+    });
 
-        let init_ident = syn::Ident::new(&format!("INIT_{item_ident}"), item_ident.span());
-        let (init_variant, init_item) = if is_async {
+    if let Some(drop_on_deinit) = &options.drop_on_deinit
+        && !matches!(item_static.mutability, syn::StaticMutability::Mut(_))
+    {
+        return Err(syn::Error::new_spanned(
+            drop_on_deinit,
+            "`#[drop_on_deinit]` requires `static mut`, since dropping the value needs exclusive access",
+        ));
+    }
+    if options.deinit.is_some() || options.drop_on_deinit.is_some() {
+        let deinit_ident = syn::Ident::new(&format!("DEINIT_{item_ident}"), item_ident.span());
+        let deinit_call = options.deinit.as_ref().map(|deinit| {
+            let deinit_func = &deinit.func;
+            let await_token = deinit.is_async.then(|| quote! { .await });
+            quote! {
+                (#deinit_func)(#value_ref)#await_token;
+            }
+        });
+        let drop_stmt = options.drop_on_deinit.as_ref().map(|_| {
+            quote! {
+                // SAFETY: Teardown runs after every dependent static has been torn down, and the
+                // value must not be accessed anymore, as required by `#[drop_on_deinit]`.
+                ::std::mem::drop(::init_static::InitStatic::take(unsafe {
+                    &mut *::std::ptr::addr_of_mut!(#item_ident)
+                }));
+            }
+        });
+        let (deinit_variant, deinit_item) = if options.deinit.as_ref().is_some_and(|deinit| deinit.is_async) {
             (
                 quote! { Async },
                 quote! {
-                    #[allow(non_snake_case)]
-                    fn #init_ident() -> ::init_static::__private::BoxFuture<::init_static::__private::anyhow::Result<()>> {
+                    #[allow(non_snake_case, clippy::explicit_auto_deref)]
+                    fn #deinit_ident() -> ::init_static::__private::BoxFuture<()> {
                         Box::pin(async {
-                            ::init_static::InitStatic::init(&#item_ident, #item_expr);
-                            Ok(())
+                            #deinit_call
+                            #drop_stmt
                         })
                     }
                 },
@@ -107,90 +594,686 @@ pub(crate) fn init_static_inner(input: TokenStream2) -> TokenStream2 {
             (
                 quote! { Sync },
                 quote! {
-                    #[allow(non_snake_case)]
-                    fn #init_ident() -> ::init_static::__private::anyhow::Result<()> {
-                        ::init_static::InitStatic::init(&#item_ident, #item_expr);
-                        Ok(())
+                    #[allow(non_snake_case, clippy::explicit_auto_deref)]
+                    fn #deinit_ident() {
+                        #deinit_call
+                        #drop_stmt
                     }
                 },
             )
         };
         inner.extend(quote! {
-            #[::init_static::__private::linkme::distributed_slice(::init_static::__private::INIT)]
+            #(#cfg_attrs)*
+            #[::init_static::__private::distributed_slice(::init_static::__private::DEINIT)]
             #[linkme(crate = ::init_static::__private::linkme)]
-            static #init_ident: ::init_static::__private::Init = {
-                #init_item
-                #deps_item
-                ::init_static::__private::Init {
-                    symbol: ::init_static::InitStatic::symbol(&#item_ident),
-                    init: ::init_static::__private::InitFn::#init_variant(#init_ident),
-                    deps: #deps_ident,
+            static #deinit_ident: ::init_static::__private::Deinit = {
+                #deinit_item
+                ::init_static::__private::Deinit {
+                    symbol: ::init_static::InitStatic::symbol(#item_ref),
+                    deinit: ::init_static::__private::DeinitFn::#deinit_variant(#deinit_ident),
                 }
             };
         });
     }
+    Ok(())
+}
 
-    quote! {
-        #output
+/// Expands a single static inside `init_thread_local!`, which is stored with `thread_local!` and
+/// registered for per-thread initialization.
+fn expand_thread_local(
+    mut item_static: syn::ItemStatic,
+    any_case_deps: bool,
+    output: &mut TokenStream2,
+    inner: &mut TokenStream2,
+) -> syn::Result<()> {
+    let options = StaticOptions::take(&mut item_static.attrs)?;
+    let (is_async, free_paths) = scan_static(&item_static, &options, any_case_deps);
+    if let syn::StaticMutability::Mut(item_mut) = &item_static.mutability {
+        return Err(syn::Error::new_spanned(item_mut, "thread-locals cannot be mutable"));
+    }
+    if is_async || options.init_async {
+        return Err(syn::Error::new_spanned(
+            &item_static.expr,
+            "thread-locals must be initialized synchronously",
+        ));
+    }
+    if let Some(deinit) = &options.deinit {
+        return Err(syn::Error::new_spanned(
+            &deinit.func,
+            "`#[deinit]` is not supported on thread-locals",
+        ));
+    }
+    if let Some(context) = &options.context {
+        return Err(syn::Error::new_spanned(
+            &context.ident,
+            "`#[context]` is not supported on thread-locals",
+        ));
+    }
+    if let Some(expose_init) = &options.expose_init {
+        return Err(syn::Error::new_spanned(
+            expose_init,
+            "`#[expose_init]` is not supported on thread-locals",
+        ));
+    }
 
-        const _: () = {
-            #inner
+    let item_vis = &item_static.vis;
+    let item_ident = &item_static.ident;
+    let item_ty = &item_static.ty;
+    let item_expr = &item_static.expr;
+    let ty_span = item_ty.span();
+    let ident_span = item_ident.span();
+    let static_ty = quote_spanned! { ty_span =>
+        ::init_static::InitThreadLocal<#item_ty>
+    };
+    let symbol = quote_spanned! { ident_span =>
+        ::init_static::Symbol!(#item_ident: #item_ty)
+    };
+    let cfg_attrs = item_static
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+        .collect::<Vec<_>>();
+    // The symbol is shared between the thread-local and its registry entry, so that both have the
+    // same `Symbol::key`.
+    let symbol_ident = syn::Ident::new(&format!("__SYMBOL_{item_ident}"), item_ident.span());
+    let item_attrs = &item_static.attrs;
+    output.extend(quote! {
+        #(#cfg_attrs)*
+        #[doc(hidden)]
+        static #symbol_ident: &::init_static::Symbol = #symbol;
+        ::std::thread_local! {
+            #(#item_attrs)*
+            #[allow(clippy::type_complexity)]
+            #item_vis static #item_ident: #static_ty = const { ::init_static::InitThreadLocal::new(#symbol_ident) };
+        }
+    });
+
+    let (deps, unmanaged_ident, deps_item) = deps_fn(item_ident, &free_paths, &options);
+    let (weak_deps, weak_deps_item) = weak_deps_fn(item_ident, &options);
+    let force_deps = force_deps(&free_paths);
+    let dependent_fn = dependent_fn(item_expr, quote! { #symbol_ident });
+    let init_allow = &options.init_allow;
+    let init_ident = syn::Ident::new(&format!("INIT_{item_ident}"), item_ident.span());
+    inner.extend(quote! {
+        #(#cfg_attrs)*
+        #[::init_static::__private::distributed_slice(::init_static::__private::THREAD_LOCAL_INIT)]
+        #[linkme(crate = ::init_static::__private::linkme)]
+        static #init_ident: ::init_static::__private::Init = {
+            #[allow(non_snake_case)]
+            #(#[allow(#init_allow)])*
+            fn #init_ident() -> ::init_static::__private::Result<()> {
+                #dependent_fn
+                #force_deps
+                let value = #item_expr;
+                #item_ident.with(|cell| ::init_static::InitThreadLocal::init(cell, value));
+                Ok(())
+            }
+            #deps_item
+            #weak_deps_item
+            ::init_static::__private::Init {
+                symbol: #symbol_ident,
+                init: ::init_static::__private::InitFn::Sync(&#init_ident),
+                deps: #deps,
+                weak_deps: #weak_deps,
+                unmanaged: &#unmanaged_ident,
+            }
         };
+    });
+    Ok(())
+}
+
+/// Scans the init expression of a static, returning whether it is async and the paths of its
+/// dependencies after applying the helper attributes.
+fn scan_static<'ast>(
+    item_static: &'ast syn::ItemStatic,
+    options: &'ast StaticOptions,
+    any_case_deps: bool,
+) -> (bool, BTreeSet<Path>) {
+    let mut is_try = false;
+    let mut is_async = false;
+    let mut free_paths = BTreeSet::new();
+    let mut scope = Scope {
+        is_try: &mut is_try,
+        is_async: &mut is_async,
+        free_paths: &mut free_paths,
+        locals: HashSet::new(),
+        any_case: any_case_deps || options.any_case_deps,
+        cfg: None,
+    };
+    if let Some(context) = &options.context {
+        scope.locals.insert(&context.ident);
+    }
+    // The declared type is visited as well, so that paths in e.g. an array length are detected.
+    scope.visit_item_static(item_static);
+    if options.no_deps {
+        free_paths.clear();
+    }
+    let ignored_deps = options.ignore_deps.iter().map(Path::new).collect::<BTreeSet<_>>();
+    free_paths.retain(|path| !ignored_deps.contains(path));
+    // Weak dependencies are only ordered if registered, even if referenced by the init expression.
+    let weak_deps = options.weak_depends.iter().map(Path::new).collect::<BTreeSet<_>>();
+    free_paths.retain(|path| !weak_deps.contains(path));
+    for path in &options.depends {
+        free_paths.replace(Path::new(path));
+    }
+    (is_async, free_paths)
+}
+
+/// Generates the dependencies of a static: a slice of functions each returning the symbol of a
+/// detected dependency, or the function given via `#[deps_fn(...)]`, which is used as is.
+///
+/// Also generates the function returning the detected dependencies that are not managed by
+/// `init_static` (neither an `InitStatic` nor a lazy type), which are reported in debug mode.
+///
+/// Whether a dependency is an `InitStatic` is only known from trait method resolution, which
+/// cannot run in a `const` context, so the symbols themselves cannot be emitted as static data. To
+/// save code size, the unmanaged dependencies are only listed in debug builds of the calling crate.
+fn deps_fn(
+    item_ident: &syn::Ident,
+    free_paths: &BTreeSet<Path>,
+    options: &StaticOptions,
+) -> (TokenStream2, TokenStream2, TokenStream2) {
+    let dynamic_deps = options
+        .deps_fn
+        .as_ref()
+        .map(|deps_fn| quote! { ::init_static::__private::Deps::Dynamic(&#deps_fn) });
+    if free_paths.is_empty() {
+        let deps = dynamic_deps.unwrap_or_else(|| quote! { ::init_static::__private::Deps::Static(&[]) });
+        return (deps, quote! { ::std::vec::Vec::new }, quote! {});
+    }
+    let unmanaged_ident = syn::Ident::new(&format!("UNMANAGED_{item_ident}"), item_ident.span());
+    let unmanaged_stmts = free_paths.iter().map(|path| {
+        let repr = path.repr.replace(' ', "");
+        let inner = &path.path;
+        path.gate(quote! {
+            (&#inner).__is_unmanaged().then_some(#repr)
+        })
+    });
+    let unmanaged_item = quote! {
+        #[cfg(debug_assertions)]
+        #[allow(non_snake_case, clippy::needless_borrow, static_mut_refs, unused_imports, unused_unsafe)]
+        fn #unmanaged_ident() -> ::std::vec::Vec<&'static str> {
+            use ::init_static::__private::MaybeInitStatic;
+            unsafe { [#(#unmanaged_stmts),*].into_iter().flatten().collect() }
+        }
+        #[cfg(not(debug_assertions))]
+        #[allow(non_snake_case)]
+        fn #unmanaged_ident() -> ::std::vec::Vec<&'static str> {
+            ::std::vec::Vec::new()
+        }
+    };
+    if let Some(deps) = dynamic_deps {
+        return (deps, quote! { #unmanaged_ident }, unmanaged_item);
+    }
+    let deps_ident = syn::Ident::new(&format!("DEPS_{item_ident}"), item_ident.span());
+    let deps_fns = free_paths.iter().map(|path| {
+        let inner = &path.path;
+        let expr = path.gate(quote! {
+            (&#inner).__get_symbol()
+        });
+        quote! { || unsafe { #expr } }
+    });
+    // Dependencies may be declared as `static mut`, which are only borrowed to read their symbol.
+    (
+        quote! { ::init_static::__private::Deps::Static(#deps_ident) },
+        quote! { #unmanaged_ident },
+        quote! {
+            #[allow(non_upper_case_globals, clippy::needless_borrow, static_mut_refs, unused_imports, unused_unsafe)]
+            static #deps_ident: &[::init_static::__private::DepFn] = {
+                use ::init_static::__private::MaybeInitStatic;
+                &[#(#deps_fns),*]
+            };
+            #unmanaged_item
+        },
+    )
+}
+
+/// Generates the dependencies listed in `#[weak_depends(...)]`, each resolved to a symbol by a
+/// function like the detected ones. They are not forced, and only add an ordering edge if they are
+/// registered with `init_static`.
+fn weak_deps_fn(item_ident: &syn::Ident, options: &StaticOptions) -> (TokenStream2, TokenStream2) {
+    if options.weak_depends.is_empty() {
+        return (quote! { &[] }, quote! {});
+    }
+    let weak_deps_ident = syn::Ident::new(&format!("WEAK_DEPS_{item_ident}"), item_ident.span());
+    let weak_deps_fns = options.weak_depends.iter().map(|path| {
+        quote! { || unsafe { (&#path).__get_symbol() } }
+    });
+    (
+        quote! { #weak_deps_ident },
+        quote! {
+            #[allow(non_upper_case_globals, clippy::needless_borrow, static_mut_refs, unused_imports, unused_unsafe)]
+            static #weak_deps_ident: &[::init_static::__private::DepFn] = {
+                use ::init_static::__private::MaybeInitStatic;
+                &[#(#weak_deps_fns),*]
+            };
+        },
+    )
+}
+
+/// Generates the function through which `dep!` invocations in an init expression learn the symbol
+/// of the static being initialized, if there are any such invocations.
+fn dependent_fn(expr: &syn::Expr, symbol: TokenStream2) -> TokenStream2 {
+    fn uses_dep(tokens: TokenStream2) -> bool {
+        let mut prev_dep = false;
+        for tt in tokens {
+            match &tt {
+                TokenTree::Group(group) if uses_dep(group.stream()) => return true,
+                TokenTree::Punct(punct) if prev_dep && punct.as_char() == '!' => return true,
+                _ => {}
+            }
+            prev_dep = matches!(&tt, TokenTree::Ident(ident) if ident == "dep");
+        }
+        false
+    }
+    if !uses_dep(expr.to_token_stream()) {
+        return quote! {};
+    }
+    quote! {
+        fn __init_static_dependent() -> &'static ::init_static::Symbol {
+            #symbol
+        }
+    }
+}
+
+/// Generates a block that forces every lazily initialized dependency (such as a `LazyLock`), so
+/// that it is initialized right before the dependent static rather than whenever it is first
+/// accessed.
+fn force_deps(free_paths: &BTreeSet<Path>) -> TokenStream2 {
+    if free_paths.is_empty() {
+        return quote! {};
+    }
+    let force_stmts = free_paths.iter().map(|path| {
+        let cfg = path.cfg.iter();
+        let path = &path.path;
+        quote! {
+            #(#[cfg(#cfg)])*
+            (&#path).__force();
+        }
+    });
+    quote! {
+        #[allow(clippy::needless_borrow, static_mut_refs, unused_imports, unused_unsafe)]
+        unsafe {
+            use ::init_static::__private::MaybeInitStatic;
+            #(#force_stmts)*
+        }
+    }
+}
+
+/// Options specified via helper attributes on a static inside `init_static!`.
+#[derive(Default)]
+struct StaticOptions {
+    /// Paths listed in `#[depends(...)]`, added to the detected dependencies.
+    depends: Vec<syn::Path>,
+    /// Paths listed in `#[weak_depends(...)]`, ordered before the static only if registered.
+    weak_depends: Vec<syn::Path>,
+    /// Paths listed in `#[ignore_deps(...)]`, removed from the detected dependencies.
+    ignore_deps: Vec<syn::Path>,
+    /// Whether `#[no_deps]` is present, which discards all detected dependencies.
+    no_deps: bool,
+    /// The function specified via `#[deps_fn(...)]`, which replaces the generated dependency list.
+    deps_fn: Option<syn::Path>,
+    /// Whether `#[any_case_deps]` is present, which disables the ALL_CAPS filter.
+    any_case_deps: bool,
+    /// Whether `#[init_async]` is present, which forces async initialization even if no `.await`
+    /// is detected.
+    init_async: bool,
+    /// The teardown function specified via `#[deinit(...)]`.
+    deinit: Option<DeinitAttr>,
+    /// The `#[drop_on_deinit]` attribute, if present, which drops the value during teardown.
+    drop_on_deinit: Option<syn::Attribute>,
+    /// The binding specified via `#[context(...)]`, through which the init expression borrows the
+    /// context passed to `init_static_with()`.
+    context: Option<ContextAttr>,
+    /// The `#[expose_init]` attribute, if present, which declares an `init_FOO()` function running
+    /// the initializer of the static, with the same visibility.
+    expose_init: Option<syn::Attribute>,
+    /// The lints listed in `#[init_allow(...)]` attributes, allowed in the generated init function.
+    init_allow: Vec<TokenStream2>,
+    /// The first static of the tuple static this static belongs to, which initializes it. This is
+    /// not set from an attribute.
+    initialized_by: Option<syn::Ident>,
+}
+
+impl StaticOptions {
+    /// Returns whether no attribute affects how the static is initialized or torn down, so that it
+    /// may be initialized at compile time.
+    fn is_plain(&self) -> bool {
+        self.depends.is_empty()
+            && self.weak_depends.is_empty()
+            && self.deps_fn.is_none()
+            && !self.init_async
+            && self.deinit.is_none()
+            && self.drop_on_deinit.is_none()
+            && self.context.is_none()
+            && self.expose_init.is_none()
+            && self.initialized_by.is_none()
+    }
+}
+
+/// Returns whether `expr` is a literal, possibly negated or nested in tuples and arrays, which can
+/// be evaluated at compile time.
+fn is_const_expr(expr: &syn::Expr) -> bool {
+    match expr {
+        syn::Expr::Lit(_) => true,
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        })
+        | syn::Expr::Paren(syn::ExprParen { expr, .. })
+        | syn::Expr::Group(syn::ExprGroup { expr, .. }) => is_const_expr(expr),
+        syn::Expr::Tuple(syn::ExprTuple { elems, .. }) | syn::Expr::Array(syn::ExprArray { elems, .. }) => {
+            elems.iter().all(is_const_expr)
+        }
+        _ => false,
     }
 }
 
-struct Path<'ast> {
-    path: &'ast syn::Path,
+/// The argument of `#[context(...)]`: the name and type of the context binding, as `ctx: Type`.
+struct ContextAttr {
+    ident: syn::Ident,
+    ty: syn::Type,
+}
+
+impl Parse for ContextAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = input.parse()?;
+        input.parse::<syn::Token![:]>()?;
+        let ty = input.parse()?;
+        Ok(Self { ident, ty })
+    }
+}
+
+/// The argument of `#[deinit(...)]`: a function taking `&T`, optionally prefixed with `async` if it
+/// returns a future.
+struct DeinitAttr {
+    is_async: bool,
+    func: syn::Expr,
+}
+
+impl Parse for DeinitAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let is_async = input.parse::<Option<syn::Token![async]>>()?.is_some();
+        let func = input.parse()?;
+        Ok(Self { is_async, func })
+    }
+}
+
+impl StaticOptions {
+    /// Removes all helper attributes from `attrs` and collects them into a [`StaticOptions`].
+    fn take(attrs: &mut Vec<syn::Attribute>) -> syn::Result<Self> {
+        let mut options = Self::default();
+        let mut result = Ok(());
+        attrs.retain(|attr| {
+            let parsed = if attr.path().is_ident("depends") {
+                parse_paths(attr).map(|paths| options.depends.extend(paths))
+            } else if attr.path().is_ident("weak_depends") {
+                parse_paths(attr).map(|paths| options.weak_depends.extend(paths))
+            } else if attr.path().is_ident("ignore_deps") {
+                parse_paths(attr).map(|paths| options.ignore_deps.extend(paths))
+            } else if attr.path().is_ident("deps_fn") {
+                attr.parse_args::<syn::Path>().map(|path| options.deps_fn = Some(path))
+            } else if attr.path().is_ident("no_deps") {
+                attr.meta.require_path_only().map(|_| options.no_deps = true)
+            } else if attr.path().is_ident("deinit") {
+                attr.parse_args::<DeinitAttr>()
+                    .map(|deinit| options.deinit = Some(deinit))
+            } else if attr.path().is_ident("drop_on_deinit") {
+                attr.meta
+                    .require_path_only()
+                    .map(|_| options.drop_on_deinit = Some(attr.clone()))
+            } else if attr.path().is_ident("any_case_deps") {
+                attr.meta.require_path_only().map(|_| options.any_case_deps = true)
+            } else if attr.path().is_ident("context") {
+                attr.parse_args::<ContextAttr>()
+                    .map(|context| options.context = Some(context))
+            } else if attr.path().is_ident("init_async") {
+                attr.meta.require_path_only().map(|_| options.init_async = true)
+            } else if attr.path().is_ident("expose_init") {
+                attr.meta
+                    .require_path_only()
+                    .map(|_| options.expose_init = Some(attr.clone()))
+            } else if attr.path().is_ident("init_allow") {
+                attr.meta
+                    .require_list()
+                    .map(|list| options.init_allow.push(list.tokens.clone()))
+            } else {
+                return true;
+            };
+            if let Err(err) = parsed {
+                result = Err(err);
+            }
+            false
+        });
+        result.map(|_| options)
+    }
+}
+
+fn parse_paths(attr: &syn::Attribute) -> syn::Result<Punctuated<syn::Path, syn::Token![,]>> {
+    attr.parse_args_with(Punctuated::parse_terminated)
+}
+
+struct Path {
+    path: syn::Path,
     repr: String,
+    /// The `#[cfg(...)]` predicate under which the path is referenced, or `None` if it is
+    /// referenced unconditionally.
+    cfg: Option<TokenStream2>,
 }
 
-impl<'ast> Path<'ast> {
-    fn new(inner: &'ast syn::Path) -> Self {
+impl Path {
+    fn new(inner: &syn::Path) -> Self {
         let repr = quote! { #inner }.to_string();
-        Self { path: inner, repr }
+        Self {
+            path: inner.clone(),
+            repr,
+            cfg: None,
+        }
+    }
+
+    /// Wraps `expr`, which evaluates to an `Option`, so that it evaluates to `None` when the path
+    /// is configured out.
+    fn gate(&self, expr: TokenStream2) -> TokenStream2 {
+        let Some(cfg) = &self.cfg else {
+            return expr;
+        };
+        quote! {
+            {
+                #[cfg(#cfg)]
+                let dep = #expr;
+                #[cfg(not(#cfg))]
+                let dep = ::std::option::Option::None;
+                dep
+            }
+        }
     }
 }
 
-impl<'ast> ::std::cmp::PartialEq for Path<'ast> {
+impl ::std::cmp::PartialEq for Path {
     fn eq(&self, other: &Self) -> bool {
         self.repr == other.repr
     }
 }
 
-impl<'ast> ::std::cmp::Eq for Path<'ast> {}
+impl ::std::cmp::Eq for Path {}
 
-impl<'ast> ::std::cmp::PartialOrd for Path<'ast> {
+impl ::std::cmp::PartialOrd for Path {
     fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl<'ast> ::std::cmp::Ord for Path<'ast> {
+impl ::std::cmp::Ord for Path {
     fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
         self.repr.cmp(&other.repr)
     }
 }
 
+/// Returns the outer attributes of an expression, such as a `#[cfg(...)]` on an expression
+/// statement.
+fn expr_attrs(expr: &syn::Expr) -> &[syn::Attribute] {
+    macro_rules! attrs {
+        ($($variant:ident),*) => {
+            match expr {
+                $(syn::Expr::$variant(expr) => &expr.attrs,)*
+                _ => &[],
+            }
+        };
+    }
+    attrs!(
+        Array, Assign, Async, Await, Binary, Block, Call, Cast, Closure, Const, Field, ForLoop, Group, If, Index, Let,
+        Lit, Loop, Macro, Match, MethodCall, Paren, Path, Range, Reference, Repeat, Return, Struct, Try, TryBlock,
+        Tuple, Unary, Unsafe, While
+    )
+}
+
 struct Scope<'a, 'ast> {
     is_try: &'a mut bool,
     is_async: &'a mut bool,
-    free_paths: &'a mut BTreeSet<Path<'ast>>,
+    free_paths: &'a mut BTreeSet<Path>,
     locals: HashSet<&'ast syn::Ident>,
+    /// Whether identifiers of any case are considered statics, not just ALL_CAPS ones.
+    any_case: bool,
+    /// The `#[cfg(...)]` predicate of the code being scanned, if any.
+    cfg: Option<TokenStream2>,
+}
+
+/// By default, we only consider ALL_CAPS identifiers as statics. With `any_case`, snake_case
+/// identifiers are considered as well, but CamelCase ones (types and variants such as `None`) are
+/// still excluded.
+fn is_static_ident(ident: &syn::Ident, any_case: bool) -> bool {
+    let repr = ident.to_string();
+    if repr == repr.to_ascii_uppercase() {
+        return true;
+    }
+    any_case && !repr.starts_with(|c: char| c.is_ascii_uppercase())
+}
+
+impl<'a, 'ast> Scope<'a, 'ast> {
+    /// Creates a nested scope whose local bindings do not leak into this one.
+    fn child(&mut self) -> Scope<'_, 'ast> {
+        Scope {
+            is_try: self.is_try,
+            is_async: self.is_async,
+            free_paths: self.free_paths,
+            locals: self.locals.clone(),
+            any_case: self.any_case,
+            cfg: self.cfg.clone(),
+        }
+    }
+
+    /// Creates a nested scope for the body of an async block or closure. An `.await` inside it is
+    /// recorded in `is_async` rather than in this scope, since it does not make the init expression
+    /// itself async.
+    fn detached<'b>(&'b mut self, is_async: &'b mut bool) -> Scope<'b, 'ast> {
+        Scope {
+            is_try: self.is_try,
+            is_async,
+            free_paths: self.free_paths,
+            locals: self.locals.clone(),
+            any_case: self.any_case,
+            cfg: self.cfg.clone(),
+        }
+    }
+
+    /// Records `path` as a dependency under the current `#[cfg(...)]` predicate. A path referenced
+    /// under several predicates is emitted when any of them holds.
+    fn insert_path(&mut self, path: &syn::Path) {
+        let mut path = Path::new(path);
+        path.cfg = self.cfg.clone();
+        if let Some(existing) = self.free_paths.take(&path) {
+            path.cfg = match (existing.cfg, path.cfg) {
+                (Some(a), Some(b)) if a.to_string() == b.to_string() => Some(a),
+                (Some(a), Some(b)) => Some(quote! { any(#a, #b) }),
+                _ => None,
+            };
+        }
+        self.free_paths.insert(path);
+    }
+
+    /// Runs `f` with the `#[cfg(...)]` predicates among `attrs` added to the current predicate, so
+    /// that dependencies found in configured-out code do not break compilation.
+    fn with_cfg(&mut self, attrs: &[syn::Attribute], f: impl FnOnce(&mut Self)) {
+        let saved = self.cfg.clone();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("cfg")) {
+            if let Ok(predicate) = attr.parse_args::<TokenStream2>() {
+                self.cfg = Some(match self.cfg.take() {
+                    Some(cfg) => quote! { all(#cfg, #predicate) },
+                    None => predicate,
+                });
+            }
+        }
+        f(self);
+        self.cfg = saved;
+    }
+
+    /// Best-effort scan of a macro body whose syntax could not be parsed as expressions.
+    ///
+    /// Every standalone ALL_CAPS identifier that is not a local is treated as a dependency.
+    /// Identifiers following `.`, `::` or `$` are skipped, as they cannot refer to a static
+    /// directly.
+    fn scan_tokens(&mut self, tokens: TokenStream2) {
+        let mut prev_punct = None;
+        for tt in tokens {
+            match &tt {
+                TokenTree::Group(group) => self.scan_tokens(group.stream()),
+                TokenTree::Ident(ident)
+                    if !matches!(prev_punct, Some('.' | ':' | '$'))
+                        && is_static_ident(ident, self.any_case)
+                        && !self.locals.contains(ident) =>
+                {
+                    self.insert_path(&ident.clone().into());
+                }
+                _ => {}
+            }
+            prev_punct = match tt {
+                TokenTree::Punct(punct) => Some(punct.as_char()),
+                _ => None,
+            };
+        }
+    }
 }
 
 impl<'i, 'ast> Visit<'ast> for Scope<'i, 'ast> {
+    fn visit_expr(&mut self, expr: &'ast syn::Expr) {
+        self.with_cfg(expr_attrs(expr), |scope| syn::visit::visit_expr(scope, expr));
+    }
+
+    fn visit_local(&mut self, local: &'ast syn::Local) {
+        self.with_cfg(&local.attrs, |scope| syn::visit::visit_local(scope, local));
+    }
+
+    fn visit_stmt_macro(&mut self, stmt_macro: &'ast syn::StmtMacro) {
+        self.with_cfg(&stmt_macro.attrs, |scope| {
+            syn::visit::visit_stmt_macro(scope, stmt_macro)
+        });
+    }
+
     fn visit_expr_path(&mut self, expr_path: &'ast syn::ExprPath) {
         if expr_path.qself.is_none()
             && self.locals.iter().all(|&ident| !expr_path.path.is_ident(ident))
-            // We only consider ALL_CAPS identifiers as statics here.
             && let Some(last_segment) = expr_path.path.segments.last()
-            && last_segment.ident == last_segment.ident.to_string().to_ascii_uppercase()
+            && is_static_ident(&last_segment.ident, self.any_case)
         {
-            self.free_paths.insert(Path::new(&expr_path.path));
+            self.insert_path(&expr_path.path);
         }
         syn::visit::visit_expr_path(self, expr_path);
     }
 
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        // Most macros (e.g. `format!`, `vec!`, `assert!`) take comma-separated expressions, so we
+        // try to parse the body as such and fall back to a token scan otherwise.
+        let Ok(exprs) = mac.parse_body_with(Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated) else {
+            self.scan_tokens(mac.tokens.clone());
+            return;
+        };
+        let mut scope = self.child();
+        for expr in &exprs {
+            scope.visit_expr(expr);
+        }
+    }
+
     fn visit_pat_ident(&mut self, pat_ident: &'ast syn::PatIdent) {
         self.locals.insert(&pat_ident.ident);
         syn::visit::visit_pat_ident(self, pat_ident);
@@ -211,15 +1294,11 @@ impl<'i, 'ast> Visit<'ast> for Scope<'i, 'ast> {
                 }
             }
         }
-        let mut scope = Scope {
-            is_try: self.is_try,
-            is_async: self.is_async,
-            free_paths: self.free_paths,
-            locals: locals.union(&self.locals).cloned().collect(),
-        };
+        let mut scope = self.child();
+        scope.locals.extend(locals);
         for stmt in &block.stmts {
             match stmt {
-                syn::Stmt::Local(local) => {
+                syn::Stmt::Local(local) => scope.with_cfg(&local.attrs, |scope| {
                     for attrs in &local.attrs {
                         scope.visit_attribute(attrs);
                     }
@@ -228,7 +1307,7 @@ impl<'i, 'ast> Visit<'ast> for Scope<'i, 'ast> {
                     }
                     scope.visit_pat(&local.pat);
                     // syn::visit::visit_local(scope, local);
-                }
+                }),
                 syn::Stmt::Expr(expr, _) => {
                     scope.visit_expr(expr);
                 }
@@ -248,12 +1327,8 @@ impl<'i, 'ast> Visit<'ast> for Scope<'i, 'ast> {
         for attrs in &expr_closure.attrs {
             self.visit_attribute(attrs);
         }
-        let mut scope = Scope {
-            is_try: self.is_try,
-            is_async: self.is_async,
-            free_paths: self.free_paths,
-            locals: self.locals.clone(),
-        };
+        let mut is_async = false;
+        let mut scope = self.detached(&mut is_async);
         for pat in &expr_closure.inputs {
             scope.visit_pat(pat);
         }
@@ -262,6 +1337,81 @@ impl<'i, 'ast> Visit<'ast> for Scope<'i, 'ast> {
         // syn::visit::visit_expr_closure(self, expr_closure);
     }
 
+    fn visit_expr_call(&mut self, expr_call: &'ast syn::ExprCall) {
+        // Functions cannot be statics, and taking a reference to a generic one may fail type
+        // inference, so skip the callee when identifiers of any case are considered.
+        if self.any_case && matches!(&*expr_call.func, syn::Expr::Path(_)) {
+            for attrs in &expr_call.attrs {
+                self.visit_attribute(attrs);
+            }
+            for arg in &expr_call.args {
+                self.visit_expr(arg);
+            }
+            return;
+        }
+        syn::visit::visit_expr_call(self, expr_call);
+    }
+
+    fn visit_arm(&mut self, arm: &'ast syn::Arm) {
+        for attrs in &arm.attrs {
+            self.visit_attribute(attrs);
+        }
+        let mut scope = self.child();
+        scope.with_cfg(&arm.attrs, |scope| {
+            scope.visit_pat(&arm.pat);
+            if let Some((_, guard)) = &arm.guard {
+                scope.visit_expr(guard);
+            }
+            scope.visit_expr(&arm.body);
+        });
+    }
+
+    fn visit_expr_let(&mut self, expr_let: &'ast syn::ExprLet) {
+        for attrs in &expr_let.attrs {
+            self.visit_attribute(attrs);
+        }
+        // The scrutinee is evaluated before the pattern binds anything.
+        self.visit_expr(&expr_let.expr);
+        self.visit_pat(&expr_let.pat);
+    }
+
+    fn visit_expr_if(&mut self, expr_if: &'ast syn::ExprIf) {
+        for attrs in &expr_if.attrs {
+            self.visit_attribute(attrs);
+        }
+        // Bindings introduced by `if let` are only visible in the `then` branch.
+        let mut scope = self.child();
+        scope.visit_expr(&expr_if.cond);
+        scope.visit_block(&expr_if.then_branch);
+        if let Some((_, else_branch)) = &expr_if.else_branch {
+            self.visit_expr(else_branch);
+        }
+    }
+
+    fn visit_expr_while(&mut self, expr_while: &'ast syn::ExprWhile) {
+        for attrs in &expr_while.attrs {
+            self.visit_attribute(attrs);
+        }
+        let mut scope = self.child();
+        scope.visit_expr(&expr_while.cond);
+        scope.visit_block(&expr_while.body);
+    }
+
+    fn visit_expr_for_loop(&mut self, expr_for_loop: &'ast syn::ExprForLoop) {
+        for attrs in &expr_for_loop.attrs {
+            self.visit_attribute(attrs);
+        }
+        self.visit_expr(&expr_for_loop.expr);
+        let mut scope = self.child();
+        scope.visit_pat(&expr_for_loop.pat);
+        scope.visit_block(&expr_for_loop.body);
+    }
+
+    fn visit_expr_async(&mut self, expr_async: &'ast syn::ExprAsync) {
+        let mut is_async = false;
+        syn::visit::visit_expr_async(&mut self.detached(&mut is_async), expr_async);
+    }
+
     fn visit_expr_try(&mut self, expr_try: &'ast syn::ExprTry) {
         *self.is_try = true;
         syn::visit::visit_expr_try(self, expr_try);
@@ -282,6 +1432,7 @@ mod test {
     use macro_expand::Context;
     use pretty_assertions::StrComparison;
     use prettyplease::unparse;
+    use trybuild::TestCases;
     use walkdir::WalkDir;
 
     use super::*;
@@ -308,6 +1459,8 @@ mod test {
             let input = read_to_string(input_path).unwrap().parse().unwrap();
             let mut ctx = Context::new();
             ctx.register_proc_macro("init_static".into(), init_static_inner);
+            ctx.register_proc_macro("init_thread_local".into(), init_thread_local_inner);
+            ctx.register_proc_macro_attribute("register".into(), |item: TokenStream2, attr| register_inner(attr, item));
             let actual = unparse(&syn::parse2(ctx.transform(input)).unwrap());
             let expect_result = read_to_string(&output_path);
             if let Ok(expect) = &expect_result
@@ -336,4 +1489,9 @@ mod test {
             panic!("Some tests failed");
         }
     }
+
+    #[test]
+    fn fail() {
+        TestCases::new().compile_fail("fixtures/fail/*.rs");
+    }
 }